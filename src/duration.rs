@@ -0,0 +1,136 @@
+//! Ranges of [`Duration`] like `"1s:5s"` or `"0ms:250ms:1s"`, each
+//! number needs a time unit suffix: `ms` (milliseconds), `s`
+//! (seconds), or `m` (minutes).
+use crate::{Number, NumberRange, NumberRangeOptions};
+use anyhow::{Context, Result};
+use std::time::Duration;
+
+const NANOS_PER_UNIT: [(&str, u64); 3] = [
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60_000_000_000),
+];
+
+/// Range of durations, the step is one second when it's not given,
+/// or one millisecond if a bound isn't in whole seconds. It's stored
+/// as a [`NumberRange<u64>`] of nanoseconds and parsed by it, so it
+/// uses the same [`NumberRangeOptions<u64>`] (the default values are
+/// in nanoseconds).
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use std::time::Duration;
+/// # use number_range::DurationRange;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let rng = DurationRange::default().parse_str("1s:3s,500ms")?;
+/// assert_eq!(
+///     rng.collect::<Vec<Duration>>(),
+///     vec![
+///         Duration::from_secs(1),
+///         Duration::from_secs(2),
+///         Duration::from_secs(3),
+///         Duration::from_millis(500)
+///     ]
+/// );
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct DurationRange<'a> {
+    pub range: NumberRange<'a, u64>,
+}
+
+impl<'a> DurationRange<'a> {
+    /// New DurationRange struct from NumberRangeOptions
+    pub fn from_options(options: NumberRangeOptions<u64>) -> Self {
+        Self {
+            range: NumberRange::from_options(options),
+        }
+    }
+
+    /// Parse the human readable string (`numstr`) of durations.
+    pub fn parse_str(mut self, numstr: &'a str) -> Result<Self> {
+        self.range.options.value_parser = Some(parse_nanos);
+        self.range = self.range.parse_str(numstr)?;
+        // every unit is more than a nanosecond, so that step can only
+        // be the implicit one
+        for num in self.range.numbers.iter_mut() {
+            match num {
+                Number::Range(start, step, end) | Number::RevRange(start, step, end)
+                    if *step == 1 =>
+                {
+                    let second = NANOS_PER_UNIT[1].1;
+                    let whole = *start % second == 0 && *end % second == 0;
+                    *step = if whole { second } else { NANOS_PER_UNIT[0].1 };
+                }
+                _ => (),
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Parses the duration with its unit into nanoseconds
+fn parse_nanos(dur: &str) -> Result<u64> {
+    let (count, nanos) = NANOS_PER_UNIT
+        .iter()
+        .find_map(|(unit, nanos)| Some((dur.strip_suffix(unit)?, *nanos)))
+        .with_context(|| format!("{} Needs a time unit (ms, s, or m)", dur))?;
+    count
+        .parse::<u64>()
+        .with_context(|| format!("{} Not a Duration", dur))?
+        .checked_mul(nanos)
+        .with_context(|| format!("{} Duration too large", dur))
+}
+
+impl<'a> Iterator for DurationRange<'a> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        self.range.next().map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1s:1s:5s", vec![1000, 2000, 3000, 4000, 5000])]
+    #[case("1s:3s", vec![1000, 2000, 3000])]
+    #[case("0ms:250ms:1s", vec![0, 250, 500, 750, 1000])]
+    #[case("2m:-30s:1m", vec![120000, 90000, 60000])]
+    #[case("1m, 10ms", vec![60000, 10])]
+    #[case("0ms:3ms", vec![0, 1, 2, 3])]
+    #[case("1500ms:-500ms:0s", vec![1500, 1000, 500, 0])]
+    #[case("", vec![])]
+    fn duration_range(#[case] numstr: &str, #[case] millis: Vec<u64>) {
+        let rng = DurationRange::default().parse_str(numstr).unwrap();
+        let expected: Vec<Duration> = millis.into_iter().map(Duration::from_millis).collect();
+        assert_eq!(rng.collect::<Vec<Duration>>(), expected);
+    }
+
+    #[rstest]
+    fn duration_range_defaults() {
+        let options = NumberRangeOptions::new()
+            .with_default_start(0)
+            .with_default_end(4_000_000_000);
+        let rng = DurationRange::from_options(options)
+            .parse_str(":1s,3s:")
+            .unwrap();
+        let expected: Vec<Duration> = [0, 1, 3, 4].into_iter().map(Duration::from_secs).collect();
+        assert_eq!(rng.collect::<Vec<Duration>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1:5")]
+    #[case("1s:2h")]
+    #[case("1s:1s:2s:3s")]
+    #[case("1s:0s:2s")]
+    #[should_panic]
+    fn duration_range_error(#[case] numstr: &str) {
+        DurationRange::default().parse_str(numstr).unwrap();
+    }
+}
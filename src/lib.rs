@@ -10,19 +10,50 @@
 //! - Configuration options for list and range separators ([`NumberRangeOptions`]).
 //!   You can also use it to provide options to parse numbers in different
 //!   localization, like grouping or different decimal separator.
+//! - Parse directly into a `Vec<T>` with default options ([`parse()`]).
+//! - Ranges of [`std::time::Duration`] like `"1s:5s"` with the
+//!   `duration` feature (`DurationRange`).
+//! - Ranges of roman numerals like `"I:V"` with the `roman` feature
+//!   (`RomanRange`).
+//! - Parse into a fixed capacity vector without allocating the
+//!   list of numbers with the `heapless` feature.
+//! - Serialize [`NumberRange<T>`] as the human readable string, and
+//!   deserialize it from the string or an array of numbers, with the
+//!   `serde` feature.
 //!
 //! # Limitations
-//! - Step size needs to be the same type as the number type, which
-//!   means you can't use negative numbers for unsigned numbers.
+//! - Step size needs to be the same type as the number type. For
+//!   unsigned numbers a negative step (e.g. `"10:-2:0"`) is parsed
+//!   as a [`SignedStep`], which makes a descending
+//!   [`Number::RevRange`] using its magnitude.
 //! - Automatic step size can only be one, not negative one as the code
 //!   is generic for unsigned too, so if you want negative step for
 //!   signed numbers you need to specify that.
 //! - Although it works with floats as well, not just integers, the
-//!   float step size might not be accurate.
+//!   float step size might not be accurate. Negative zero is equal
+//!   to zero, so `"-0:0"` only has one value (`-0.0`).
+//! - The step can't be zero (`"5:0:5"` is an error). Ranges with the
+//!   same start and end (`"5:5"` or `"5:2:5"`) have just that value,
+//!   unless `empty_on_equal_bounds` is set in [`NumberRangeOptions`].
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
-use std::collections::VecDeque;
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+#[cfg(feature = "duration")]
+mod duration;
+#[cfg(feature = "duration")]
+pub use duration::DurationRange;
+mod progression;
+use progression::{intersections, symmetric_difference, union_count, Progression};
+#[cfg(feature = "roman")]
+mod roman;
+#[cfg(feature = "roman")]
+pub use roman::RomanRange;
+#[cfg(feature = "serde")]
+mod serialize;
 
 #[derive(Debug)]
 pub struct NumberRangeError;
@@ -35,6 +66,68 @@ impl std::fmt::Display for NumberRangeError {
     }
 }
 
+/// Specific errors from parsing, they are the root cause of the
+/// errors returned while parsing, so you can check them with
+/// [`anyhow::Error::downcast_ref`].
+///
+/// ```rust
+/// # use number_range::{NumberRangeOptions, ParseError};
+/// let err = NumberRangeOptions::<f64>::new().parse("nan:5").unwrap_err();
+/// assert_eq!(err.downcast_ref::<ParseError>(), Some(&ParseError::NonFiniteBound));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Start or end of a range is `NaN` or infinite
+    NonFiniteBound,
+    /// More list separated segments than the `max_segments` option
+    TooManySegments,
+    /// Step of a range is zero, so it'd never reach the end
+    ZeroStep,
+    /// Number with a decimal part for an integer type
+    FractionalForInteger { token: String },
+    /// Start or end of a range is omitted without its default value
+    MissingBound { side: BoundSide },
+}
+
+/// Start or end of a range, for [`ParseError::MissingBound`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundSide {
+    Start,
+    End,
+}
+
+impl std::fmt::Display for BoundSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BoundSide::Start => write!(f, "start"),
+            BoundSide::End => write!(f, "end"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NonFiniteBound => write!(f, "Range bound is not a finite number"),
+            ParseError::TooManySegments => write!(f, "Too many segments in the list"),
+            ParseError::ZeroStep => write!(f, "Step of the range is zero"),
+            ParseError::FractionalForInteger { token } => write!(
+                f,
+                "{} has a decimal part for an integer type, use a float type (e.g. f64)",
+                token
+            ),
+            ParseError::MissingBound { side } => write!(
+                f,
+                "Range {0} is omitted without the default_{0} option",
+                side
+            ),
+        }
+    }
+}
+
 /// Number type for simple interger numbers or number range. The
 /// [`NumberRange<T>`] is made up of these, so you can use it to build
 /// the [`NumberRange<T>`] manually.
@@ -53,10 +146,111 @@ impl std::fmt::Display for NumberRangeError {
 /// #     Ok(())
 /// # }
 /// ```
-#[derive(Debug)]
+///
+/// [`Number::RevRange`] is a descending range where the step is the
+/// magnitude to subtract each time, it is how the negative steps are
+/// stored for unsigned numbers.
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use number_range::{NumberRange,Number};
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let mut rng = NumberRange::<usize>::default();
+/// rng.numbers.push_back(Number::RevRange(10,3,0));
+/// assert_eq!(format!("{}", rng), "10:-3:0");
+/// assert_eq!(rng.collect::<Vec<usize>>(), vec![10, 7, 4, 1]);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Number::Repeat`] is the value repeated the given number of
+/// times, it's written as `count` and `value` separated by the
+/// `repeat_marker` (`x` if it's not set).
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use number_range::{NumberRange,Number};
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let mut rng = NumberRange::<usize>::default();
+/// rng.numbers.push_back(Number::Repeat(5, 3));
+/// assert_eq!(format!("{}", rng), "3x5");
+/// assert_eq!(rng.collect::<Vec<usize>>(), vec![5, 5, 5]);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// The enum is `#[non_exhaustive]`, since [`Number::RevRange`] and
+/// [`Number::Repeat`] were added after `0.3.2` it was already a
+/// breaking change for the exhaustive matches, they now need a
+/// wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub enum Number<T> {
     Single(T),
     Range(T, T, T),
+    RevRange(T, T, T),
+    Repeat(T, usize),
+}
+
+/// Step of a range that can be negative for any number type, stored
+/// as its magnitude and direction. This is how the unsigned numbers
+/// get the negative steps, the values go down by the magnitude and
+/// stop before going past the end (or below zero).
+///
+/// ```rust
+/// # use number_range::{Number, SignedStep};
+/// let step = SignedStep::<usize>::from_signed(-2isize).unwrap();
+/// assert_eq!(step, SignedStep::new(2, true));
+/// let num = Number::with_signed_step(10, step, 0);
+/// assert_eq!(num, Number::RevRange(10, 2, 0));
+/// assert_eq!(num.iter().collect::<Vec<usize>>(), vec![10, 8, 6, 4, 2, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedStep<T> {
+    /// Size of the step
+    pub magnitude: T,
+    /// Direction of the step, the values go down when it's set
+    pub negative: bool,
+}
+
+impl<T> SignedStep<T> {
+    /// New SignedStep from its magnitude and direction
+    pub fn new(magnitude: T, negative: bool) -> Self {
+        Self {
+            magnitude,
+            negative,
+        }
+    }
+}
+
+impl<T: num::PrimInt> SignedStep<T> {
+    /// Step from a signed number (e.g. an `isize` step for `usize`
+    /// values), `None` if its magnitude doesn't fit in `T`.
+    ///
+    /// ```rust
+    /// # use number_range::SignedStep;
+    /// assert_eq!(SignedStep::<u8>::from_signed(-128i8), Some(SignedStep::new(128, true)));
+    /// assert_eq!(SignedStep::<u8>::from_signed(3i64), Some(SignedStep::new(3, false)));
+    /// assert_eq!(SignedStep::<u8>::from_signed(-300i64), None);
+    /// ```
+    pub fn from_signed<S: num::PrimInt + num::Signed>(step: S) -> Option<Self> {
+        let step = step.to_i128()?;
+        Some(Self::new(T::from(step.unsigned_abs())?, step < 0))
+    }
+}
+
+impl<T> Number<T> {
+    /// Range from `start` to `end` with the [`SignedStep`], the
+    /// negative step makes a [`Number::RevRange`].
+    pub fn with_signed_step(start: T, step: SignedStep<T>, end: T) -> Self {
+        if step.negative {
+            Number::RevRange(start, step.magnitude, end)
+        } else {
+            Number::Range(start, step.magnitude, end)
+        }
+    }
 }
 
 impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
@@ -70,6 +264,8 @@ impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
     /// assert!(Number::Single(1).is_valid());
     /// assert!(Number::Range(3,2,6).is_valid());
     /// assert!(Number::Range(-4,1,-2).is_valid());
+    /// assert!(Number::RevRange(6,2,3).is_valid());
+    /// assert!(Number::Repeat(6,2).is_valid());
     /// #     Ok(())
     /// # }
     /// ```
@@ -80,6 +276,8 @@ impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
                 ((start <= end) && (step > &num::Zero::zero()))
                     || ((start >= end) && (step < &num::Zero::zero()))
             }
+            Number::RevRange(start, step, end) => (start >= end) && (step > &num::Zero::zero()),
+            Number::Repeat(_, count) => *count > 0,
         }
     }
     /// Opposite of is_valid
@@ -91,6 +289,7 @@ impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// assert!(Number::Range(3,-2,6).is_invalid());
     /// assert!(Number::Range(4,1,2).is_invalid());
+    /// assert!(Number::RevRange(2,1,4).is_invalid());
     /// #     Ok(())
     /// # }
     /// ```
@@ -99,6 +298,316 @@ impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
     }
 }
 
+impl<
+        T: Copy
+            + std::ops::Add<Output = T>
+            + std::ops::Sub<Output = T>
+            + std::cmp::PartialOrd
+            + num::Zero,
+    > Number<T>
+{
+    /// First value of the number/range and what remains after it,
+    /// invalid ranges don't have any values.
+    fn split_first(&self) -> Option<(T, Option<Number<T>>)> {
+        if self.is_invalid() {
+            return None;
+        }
+        match *self {
+            Number::Single(v) => Some((v, None)),
+            Number::Range(start, step, end) => {
+                let zero = T::zero();
+                // the next value can't overflow when it goes towards
+                // the other sign, otherwise the difference can't
+                let has_next = if (start < zero) != (end < zero) {
+                    Number::Range(start + step, step, end).is_valid()
+                } else if step > zero {
+                    end - start >= step
+                } else {
+                    end - start <= step
+                };
+                Some((
+                    start,
+                    has_next.then(|| Number::Range(start + step, step, end)),
+                ))
+            }
+            Number::RevRange(start, step, end) => {
+                // comparing the difference so unsigned numbers don't go
+                // below zero, it only overflows for the bounds on
+                // different sides of zero, where the next value can't
+                let zero = T::zero();
+                let has_next = if (start < zero) != (end < zero) {
+                    start - step >= end
+                } else {
+                    start - end >= step
+                };
+                let rest = has_next.then(|| Number::RevRange(start - step, step, end));
+                Some((start, rest))
+            }
+            Number::Repeat(v, count) => {
+                Some((v, (count > 1).then(|| Number::Repeat(v, count - 1))))
+            }
+        }
+    }
+
+    /// Iterator through the values of the number/range without
+    /// consuming it.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::Number;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(Number::Range(3,2,8).iter().collect::<Vec<i64>>(), vec![3, 5, 7]);
+    /// assert_eq!(Number::RevRange(8,2,3).iter().collect::<Vec<u64>>(), vec![8, 6, 4]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        std::iter::successors(self.split_first(), |(_, rest)| {
+            rest.as_ref().and_then(Number::split_first)
+        })
+        .map(|(value, _)| value)
+    }
+}
+
+impl<T: num::PrimInt> Number<T> {
+    /// Distance from `low` to `high` (not less than `low`), `u128`
+    /// holds the span of any primitive integer type.
+    fn distance(low: T, high: T) -> u128 {
+        match (low.to_i128(), high.to_i128()) {
+            (Some(low), Some(high)) => high.wrapping_sub(low) as u128,
+            // only the u128 values don't fit, they're not negative
+            _ => high.to_u128().unwrap_or(u128::MAX) - low.to_u128().unwrap_or(0),
+        }
+    }
+
+    /// Distance of the value from the smallest value of the type, as
+    /// its key in the [`Progression`]s.
+    fn key(value: T) -> u128 {
+        Self::distance(T::min_value(), value)
+    }
+
+    /// Values as a [`Progression`] of their keys, `None` when there
+    /// are no values.
+    fn progression(&self) -> Option<Progression> {
+        let (first, last) = (Self::key(self.advance(0)?), Self::key(self.last()?));
+        let step = match *self {
+            Number::Range(_, step, _) | Number::RevRange(_, step, _) => Self::magnitude(step),
+            _ => 1,
+        };
+        Progression::new(first.min(last), step, first.max(last))
+    }
+
+    /// Size of the step, without its sign.
+    fn magnitude(step: T) -> u128 {
+        if step < T::zero() {
+            Self::distance(step, T::zero())
+        } else {
+            Self::distance(T::zero(), step)
+        }
+    }
+
+    /// The `value` moved up by `off`, `None` if it goes past the
+    /// largest value of the type.
+    fn forward(value: T, off: u128) -> Option<T> {
+        if off > Self::distance(value, T::max_value()) {
+            return None;
+        }
+        match value.to_i128() {
+            Some(v) if v < 0 => T::from((v as u128).wrapping_add(off) as i128),
+            _ => T::from(value.to_u128()? + off),
+        }
+    }
+
+    /// The `value` moved down by `off`, `None` if it goes past the
+    /// smallest value of the type.
+    fn backward(value: T, off: u128) -> Option<T> {
+        if off > Self::distance(T::min_value(), value) {
+            return None;
+        }
+        match value.to_u128() {
+            Some(v) if v >= off => T::from(v - off),
+            _ => T::from((value.to_i128()? as u128).wrapping_sub(off) as i128),
+        }
+    }
+
+    /// Number of steps after the first value, `None` if there are no
+    /// values.
+    fn steps(&self) -> Option<u128> {
+        if self.is_invalid() {
+            return None;
+        }
+        Some(match *self {
+            Number::Single(_) => 0,
+            Number::Range(start, step, end) if step > T::zero() => {
+                Self::distance(start, end) / Self::magnitude(step)
+            }
+            Number::Range(start, step, end) | Number::RevRange(start, step, end) => {
+                Self::distance(end, start) / Self::magnitude(step)
+            }
+            Number::Repeat(_, count) => count as u128 - 1,
+        })
+    }
+
+    /// Value after the given number of steps from the first one.
+    fn advance(&self, steps: u128) -> Option<T> {
+        match *self {
+            Number::Single(v) | Number::Repeat(v, _) => Some(v),
+            Number::Range(start, step, _) if step > T::zero() => {
+                Self::forward(start, steps.checked_mul(Self::magnitude(step))?)
+            }
+            Number::Range(start, step, _) | Number::RevRange(start, step, _) => {
+                Self::backward(start, steps.checked_mul(Self::magnitude(step))?)
+            }
+        }
+    }
+
+    /// Number of values in the number/range, computed without
+    /// iterating. It saturates at `usize::MAX` for the larger ranges.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::Number;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(Number::Single(4).count(), 1);
+    /// assert_eq!(Number::Range(3,2,8).count(), 3);
+    /// assert_eq!(Number::Range(8,-3,1).count(), 3);
+    /// assert_eq!(Number::Range(8,3,1).count(), 0);
+    /// assert_eq!(Number::Range(0,1,u128::MAX).count(), usize::MAX);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn count(&self) -> usize {
+        match self.steps() {
+            Some(steps) => usize::try_from(steps)
+                .ok()
+                .and_then(|s| s.checked_add(1))
+                .unwrap_or(usize::MAX),
+            None => 0,
+        }
+    }
+
+    /// Last value of the number/range, which can be different from
+    /// the end when the step doesn't land on it.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::Number;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(Number::Range(3,2,8).last(), Some(7));
+    /// assert_eq!(Number::Range(8,3,1).last(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn last(&self) -> Option<T> {
+        self.advance(self.steps()?)
+    }
+
+    /// Value at the index `n` of the number/range, without iterating.
+    fn nth(&self, n: usize) -> Option<T> {
+        if n >= self.count() {
+            return None;
+        }
+        self.advance(n as u128)
+    }
+
+    /// Index of the `value` in the number/range, without iterating.
+    fn index_of(&self, value: T) -> Option<usize> {
+        let (offset, step) = match *self {
+            Number::Single(v) | Number::Repeat(v, _) => {
+                return (value == v).then_some(0);
+            }
+            Number::Range(start, step, _) if step > T::zero() => {
+                if value < start {
+                    return None;
+                }
+                (Self::distance(start, value), Self::magnitude(step))
+            }
+            Number::Range(start, step, _) | Number::RevRange(start, step, _) => {
+                if value > start {
+                    return None;
+                }
+                (Self::distance(value, start), Self::magnitude(step))
+            }
+        };
+        if offset % step != 0 || offset / step > self.steps()? {
+            return None;
+        }
+        usize::try_from(offset / step).ok()
+    }
+
+    /// Part of the number/range with the values from the index
+    /// `from` till before `to`, `None` if it's empty.
+    fn slice(&self, from: usize, to: usize) -> Option<Number<T>> {
+        let to = to.min(self.count());
+        if from >= to {
+            return None;
+        }
+        let (first, last) = (self.nth(from)?, self.nth(to - 1)?);
+        Some(match *self {
+            _ if from + 1 == to => Number::Single(first),
+            Number::Range(_, step, _) => Number::Range(first, step, last),
+            Number::RevRange(_, step, _) => Number::RevRange(first, step, last),
+            Number::Repeat(v, _) => Number::Repeat(v, to - from),
+            Number::Single(v) => Number::Single(v),
+        })
+    }
+
+    /// Same number/range with only the values between `low` and
+    /// `high` (inclusive), `None` if there are no values left.
+    fn clamp(&self, low: T, high: T) -> Option<Number<T>> {
+        let (first, step, last) = match self.ascending()? {
+            Number::Single(v) | Number::Repeat(v, _) => (v, T::one(), v),
+            Number::Range(start, step, end) => (start, step, end),
+            Number::RevRange(..) => unreachable!("ascending never gives RevRange"),
+        };
+        if last < low || first > high {
+            return None;
+        }
+        let step_size = Self::magnitude(step);
+        // steps needed to get from the value to past the bound
+        let steps_over = |d: u128| d.div_ceil(step_size);
+        let first = if first < low {
+            let off = steps_over(Self::distance(first, low)).checked_mul(step_size)?;
+            Self::forward(first, off)?
+        } else {
+            first
+        };
+        let last = if last > high {
+            let off = steps_over(Self::distance(high, last)).checked_mul(step_size)?;
+            Self::backward(last, off)?
+        } else {
+            last
+        };
+        if first > last {
+            return None;
+        }
+        Some(match *self {
+            Number::Single(_) | Number::Repeat(..) => *self,
+            Number::Range(_, step, _) if step < T::zero() => Number::Range(last, step, first),
+            Number::Range(_, step, _) => Number::Range(first, step, last),
+            Number::RevRange(_, step, _) => Number::RevRange(last, step, first),
+        })
+    }
+
+    /// Same values of the number/range but in ascending order.
+    fn ascending(&self) -> Option<Number<T>> {
+        let last = self.last()?;
+        match *self {
+            Number::Single(_) | Number::Repeat(..) => Some(*self),
+            Number::Range(start, step, _) if step > T::zero() => {
+                Some(Number::Range(start, step, last))
+            }
+            Number::Range(start, step, _) => {
+                Some(Number::Range(last, T::zero().checked_sub(&step)?, start))
+            }
+            Number::RevRange(start, step, _) => Some(Number::Range(last, step, start)),
+        }
+    }
+}
+
 /// Options for the NumberRange, includes different separator
 /// character customization.
 ///
@@ -168,9 +677,19 @@ impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
 /// ```
 ///
 /// All the numbers in the string must be of the same type that you
-/// want to parse into, due to that restriction even the step needs to
-/// be unsigned for unsigned number (meaning `"4:-1:1"` would fail
-/// even if the final output should be unsigned).
+/// want to parse into, except for the negative step on unsigned
+/// numbers, which makes a descending range (meaning `"4:-1:1"` gives
+/// `[4, 3, 2, 1]` even for unsigned numbers).
+/// ```rust
+/// # use std::error::Error;
+/// # use number_range::NumberRangeOptions;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// assert_eq!(NumberRangeOptions::<usize>::new()
+///              .parse("4:-1:1")?.collect::<Vec<usize>>(), vec![4,3,2,1]);
+/// #     Ok(())
+/// # }
+/// ```
 ///
 /// Another function is to parse the numbers in different localization
 /// like different decimal separators or grouping of numbers.
@@ -189,20 +708,31 @@ impl<T: num::Zero + std::cmp::PartialOrd + Copy> Number<T> {
 /// #     Ok(())
 /// # }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NumberRangeOptions<T> {
     /// Character used to group numbers [default: `_`]. Group
-    /// separator is the first one to be removed from the string, if
-    /// any other characters are same as the group separators then
-    /// they'll be useless.
+    /// separator is removed from each number after the string is
+    /// split into the list and the ranges, so it can't be the same
+    /// as those separators, but the range bounds can be grouped
+    /// (e.g. `1,000-2,000`). Spaces (or thin spaces `\u{2009}`) can
+    /// be used as well, as the numbers are trimmed before that.
     pub group_sep: char,
+    /// Number of digits in each group [default: `3`], only the first
+    /// group can have fewer digits. Only checked when
+    /// `validate_groups` is set.
+    pub group_size: usize,
+    /// Check the grouping of the numbers with `group_size`, and
+    /// error on numbers grouped differently [default: `false`].
+    pub validate_groups: bool,
     /// Remove spaces between the numbers. While spaces are removed
     /// after the group separator. If any other separator characters
     /// are whitespace they'll be useless.
     pub whitespace: bool,
     /// Decimal separator [default: `.`]. Decimal separator is
     /// replaced by `.` for rust to parse the float properly. The
-    /// replacement occurs after the whitespace removal.
+    /// replacement occurs after the whitespace removal. Numbers can
+    /// start with the decimal separator even when they are negative
+    /// (e.g. `-.5`, or `-,5` with `,` as decimal separator).
     pub decimal_sep: char,
     /// Separator for different numbers or numbers range [default:
     /// `,`]. List separator is used to split first.
@@ -213,11 +743,101 @@ pub struct NumberRangeOptions<T> {
     /// meaning.
     pub range_sep: char,
     /// Default start value, if the start value is ommited in a range,
-    /// it'll be used
+    /// it'll be used (e.g. `":5"` or `":"`). Omitting it without the
+    /// default is an error ([`ParseError::MissingBound`]), the range
+    /// is never skipped.
     pub default_start: Option<T>,
     /// Default end value, if the end value is ommited in a range,
-    /// it'll be used
+    /// it'll be used (e.g. `"5:"` or `":"`). Omitting it without the
+    /// default is an error ([`ParseError::MissingBound`]), the range
+    /// is never skipped.
     pub default_end: Option<T>,
+    /// Default step value, if the step is ommited in a range with
+    /// step (e.g. `1::10`) it'll be used instead of one. The ranges
+    /// without step (e.g. `1:10`) always have the step of one.
+    pub default_step: Option<T>,
+    /// Parse the step ending with `%` as the percentage of the
+    /// range span (`"0:10%:100"` has a step of `10`). For integers
+    /// the step must come out exact [default: `false`].
+    pub percent_step: bool,
+    /// Parse the mathematical intervals like `[1,5)`, where the
+    /// bounds are separated by the list separator and the brackets
+    /// tell if the ends are inclusive `[]` or exclusive `()`
+    /// [default: `false`].
+    pub math_intervals: bool,
+    /// Parse the single numbers as the range till the default end
+    /// value, or if it's not given, from the default start value
+    /// [default: `false`].
+    pub single_as_range: bool,
+    /// Confirms that the range separator being the same as the
+    /// decimal separator is intended, in which case the character is
+    /// only used to separate the ranges. Otherwise having the same
+    /// character for both is an error [default: `false`].
+    pub range_sep_distinct: bool,
+    /// Maximum number of list separated segments, more than that is
+    /// an error. Useful to limit untrusted inputs [default: `None`].
+    pub max_segments: Option<usize>,
+    /// Maximum number of values in each number/range, more than that
+    /// is an error. It's counted without iterating [default: `None`].
+    pub max_segment_count: Option<usize>,
+    /// Parse the keywords `first` and `last` as the default start
+    /// and end values, it's an error if they're not given
+    /// [default: `false`].
+    pub keywords: bool,
+    /// Character between the count and the value to repeat, like
+    /// `x` for `3x5` (5 three times) [default: `None`].
+    pub repeat_marker: Option<char>,
+    /// Offset added to the values (not the steps), the numbers the
+    /// type can't hold are parsed as `i128` first, so negative values
+    /// can be stored in unsigned types [default: `None`]. Default
+    /// values are used as they are.
+    pub offset: Option<i64>,
+    /// Make the ranges with the same start and end (e.g. `"5:5"` or
+    /// `"5:2:5"`) empty instead of having that one value. They're
+    /// left out like the other empty segments [default: `false`].
+    pub empty_on_equal_bounds: bool,
+    /// Separator for the ranges without the end [default: `None`],
+    /// like `;` for `1;5` to be `1:4`. The end value is left out
+    /// when the steps land on it (`0;0.5;2` stops at `1.5`).
+    pub exclusive_range_sep: Option<char>,
+    /// Parse the numbers in parentheses as negative, like `(5)` for
+    /// `-5` in accounting [default: `false`]. The math intervals are
+    /// checked before this, so they don't work together.
+    pub accounting_negatives: bool,
+    /// Allow the numbers with the decimal separator, otherwise they
+    /// are an error even for the float types [default: `true`].
+    pub decimals: bool,
+    /// Marker between the center and the radius of a range, like `±`
+    /// for `100±5` to be `95:105`. The ASCII `+-` also works when
+    /// the marker is set [default: `None`].
+    pub pm_marker: Option<char>,
+    /// Characters around the step that make it the number of values
+    /// instead, like `|` for `1:|5|:9` to be `1:2:9`. For integers
+    /// the step must come out exact [default: `None`].
+    pub count_delimiters: Option<(char, char)>,
+    /// Make the ranges without the step descending (e.g. `"10:1"` is
+    /// `10:-1:1`), as well as the ones with the omitted step when
+    /// there is no default step [default: `false`].
+    pub descending: bool,
+    /// Swap the start and end of the ranges that go in the other
+    /// direction than their step (e.g. `"5:1"` is `1:5`), instead of
+    /// them having no values. The values can be different from the
+    /// ones in the reverse order when the step doesn't land on the
+    /// end (`"10:3:1"` is `1:3:10`) [default: `false`].
+    pub autofix_direction: bool,
+    /// Number of values in the ranges with the `auto` step (e.g.
+    /// `"1:auto:9"`), the step is chosen to fit them. For integers
+    /// the step must come out exact [default: `None`].
+    pub target_count: Option<usize>,
+    /// Parse the ranges ending with `i` or `e` as having the
+    /// inclusive or exclusive end (e.g. `"1:5e"` is `1:4`), the
+    /// suffix overrides the range separator used [default: `false`].
+    pub inclusivity_suffixes: bool,
+    /// Parser for the numbers (values and steps) instead of
+    /// [`str::parse()`], it gets them after they're sanitized. Useful
+    /// for the numbers with units or in other forms, like `250ms`
+    /// [default: `None`].
+    pub value_parser: Option<fn(&str) -> Result<T>>,
 }
 
 /// Representation of Number Ranges, once you've parsed the string you
@@ -262,619 +882,6598 @@ pub struct NumberRangeOptions<T> {
 #[derive(Debug)]
 pub struct NumberRange<'a, T> {
     pub numbers: VecDeque<Number<T>>,
-    original_repr: Option<&'a str>,
+    original_repr: Option<Cow<'a, str>>,
+    padding_width: Option<usize>,
+    parse_seps: Option<(char, char)>,
+    relative_base: Option<T>,
     pub options: NumberRangeOptions<T>,
 }
 
+/// [`NumberRange<T>`] that owns its original string, made with
+/// [`NumberRange::parse_owned()`], so it can be stored without the
+/// lifetime of the input.
+pub type OwnedNumberRange<T> = NumberRange<'static, T>;
+
 impl<'a, T: std::fmt::Display + num::One + std::cmp::PartialEq> std::fmt::Display
     for NumberRange<'a, T>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let repr = self
-            .numbers
-            .iter()
-            .map(|n| match n {
-                Number::Single(v) => format!("{}", v),
-                Number::Range(s, i, e) => {
-                    if i.is_one() {
-                        format!("{}{}{}", s, self.options.range_sep, e)
-                    } else {
-                        format!("{}{}{}{1}{}", s, self.options.range_sep, i, e)
-                    }
-                }
-            })
-            .join(&self.options.list_sep.to_string());
+        let repr =
+            self.format_numbers(self.options.list_sep, self.options.range_sep, false, &|v| {
+                v.to_string()
+            });
         write!(f, "{}", repr)
     }
 }
 
-impl<'a, T: Copy + std::ops::Add<Output = T> + std::cmp::PartialOrd + num::Zero> Iterator
-    for NumberRange<'a, T>
-{
-    type Item = T;
-
-    fn next(&mut self) -> Option<T> {
-        if self.numbers.is_empty() {
-            return None;
-        }
-        match self.numbers[0] {
-            Number::Single(v) => {
-                self.numbers.pop_front();
-                Some(v)
-            }
-            Number::Range(start, step, end) => {
-                // checking this one coz people can insert their invalid ranges or parse invalid ones
-                if self.numbers[0].is_valid() {
-                    let next_step = Number::Range(start + step, step, end);
-                    // checking here to always have valid steps
-                    if next_step.is_valid() {
-                        self.numbers[0] = next_step;
-                    } else {
-                        self.numbers.pop_front();
-                    }
-                    Some(start)
+impl<'a, T: std::fmt::Display + num::One + std::cmp::PartialEq> NumberRange<'a, T> {
+    /// String representation of a number/range with the values
+    /// formatted by `fmt`, the step of one is only shown if
+    /// `explicit_step` is true.
+    fn format_number_with(
+        &self,
+        n: &Number<T>,
+        range_sep: char,
+        explicit_step: bool,
+        fmt: &dyn Fn(&T) -> String,
+    ) -> String {
+        match n {
+            Number::Single(v) => fmt(v),
+            Number::Range(s, i, e) => {
+                if i.is_one() && !explicit_step {
+                    format!("{}{}{}", fmt(s), range_sep, fmt(e))
                 } else {
-                    self.numbers.pop_front();
-                    self.next()
+                    format!("{}{}{}{1}{}", fmt(s), range_sep, fmt(i), fmt(e))
                 }
             }
+            Number::RevRange(s, i, e) => {
+                format!("{}{}-{}{1}{}", fmt(s), range_sep, fmt(i), fmt(e))
+            }
+            Number::Repeat(v, count) => {
+                format!(
+                    "{}{}{}",
+                    count,
+                    self.options.repeat_marker.unwrap_or('x'),
+                    fmt(v)
+                )
+            }
         }
     }
-}
-
-impl<
-        T: std::str::FromStr
-            + num::One
-            + Copy
-            + std::str::FromStr
-            + std::cmp::PartialOrd
-            + std::ops::Add<Output = T>,
-    > Default for NumberRangeOptions<T>
-{
-    fn default() -> Self {
-        Self::new()
-    }
-}
 
-impl<T: std::str::FromStr + num::One + Copy + std::cmp::PartialOrd + std::ops::Add<Output = T>>
-    NumberRangeOptions<T>
-{
-    /// New struct with default options
-    pub fn new() -> Self {
-        Self {
-            list_sep: ',',
-            range_sep: ':',
-            decimal_sep: '.',
-            group_sep: '_',
-            whitespace: false,
-            default_start: None,
-            default_end: None,
-        }
+    /// String representation of a number/range with the separator
+    /// from the options.
+    fn format_number(&self, n: &Number<T>, explicit_step: bool) -> String {
+        self.format_number_with(n, self.options.range_sep, explicit_step, &|v| v.to_string())
     }
 
-    /// Change the group separator character
-    pub fn with_group_sep(mut self, sep: char) -> Self {
-        self.group_sep = sep;
-        self
+    /// String representation of all the numbers with the given
+    /// separators and the function to format the values.
+    fn format_numbers(
+        &self,
+        list_sep: char,
+        range_sep: char,
+        explicit_step: bool,
+        fmt: &dyn Fn(&T) -> String,
+    ) -> String {
+        self.numbers
+            .iter()
+            .map(|n| self.format_number_with(n, range_sep, explicit_step, fmt))
+            .join(&list_sep.to_string())
     }
 
-    /// Change the group separator character
-    pub fn with_whitespace(mut self, flag: bool) -> Self {
-        self.whitespace = flag;
-        self
+    /// String representation that always has the step in the
+    /// ranges, even when it is one.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:5,7,8:2:12")?;
+    /// assert_eq!(rng.to_string_explicit(), "1:1:5,7,8:2:12");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_string_explicit(&self) -> String {
+        self.format_numbers(self.options.list_sep, self.options.range_sep, true, &|v| {
+            v.to_string()
+        })
     }
 
-    /// Change the decimal separator character
-    pub fn with_decimal_sep(mut self, sep: char) -> Self {
-        self.decimal_sep = sep;
-        self
+    /// String representation with the list and range separators used
+    /// while parsing, even if the options were changed later. It's
+    /// same as the [`std::fmt::Display`] when it's not parsed.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,NumberRangeOptions};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut rng = NumberRangeOptions::<i64>::new()
+    ///              .with_range_sep('-')
+    ///              .parse("1-5,8")?;
+    /// rng.options.range_sep = ':';
+    /// assert_eq!(rng.to_string(), "1:5,8");
+    /// assert_eq!(rng.to_canonical_string(), "1-5,8");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        let (list_sep, range_sep) = self
+            .parse_seps
+            .unwrap_or((self.options.list_sep, self.options.range_sep));
+        self.format_numbers(list_sep, range_sep, false, &|v| v.to_string())
     }
 
-    /// Change the list separator character
-    pub fn with_list_sep(mut self, sep: char) -> Self {
-        self.list_sep = sep;
-        self
+    /// String representation with the digits of the values grouped
+    /// by `group_size` using the `group_sep` from the options.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,NumberRangeOptions};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRangeOptions::<i64>::new()
+    ///              .with_list_sep('/')
+    ///              .with_group_sep(',')
+    ///              .parse("1,200:1,205/12000")?;
+    /// assert_eq!(rng.to_grouped_string(), "1,200:1,205/12,000");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_grouped_string(&self) -> String {
+        let sep = self.options.group_sep;
+        let size = self.options.group_size;
+        self.format_numbers(self.options.list_sep, self.options.range_sep, false, &|v| {
+            group_digits(&v.to_string(), sep, size)
+        })
     }
 
-    /// Change the range separator character
-    pub fn with_range_sep(mut self, sep: char) -> Self {
-        self.range_sep = sep;
-        self
+    /// String representation with the values padded with zeros to
+    /// the `width`, use [`NumberRange::detected_width()`] to keep
+    /// the padding of the parsed string.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("001:010,15")?;
+    /// assert_eq!(rng.to_padded_string(3), "001:010,015");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_padded_string(&self, width: usize) -> String {
+        self.format_numbers(self.options.list_sep, self.options.range_sep, false, &|v| {
+            format!("{:0width$}", v)
+        })
     }
 
-    /// Include a default start value
-    pub fn with_default_start(mut self, def: T) -> Self {
-        self.default_start = Some(def);
-        self
+    /// String representation like the one used in git and diff
+    /// (e.g. `1-5,8`), regardless of the separators in the
+    /// options. Ranges with steps other than one are written as
+    /// `start:step:end`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:5,8,10:2:14")?;
+    /// assert_eq!(rng.to_git_style(), "1-5,8,10:2:14");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_git_style(&self) -> String {
+        self.numbers
+            .iter()
+            .map(|n| {
+                let range_sep = match n {
+                    Number::Range(_, step, _) if step.is_one() => '-',
+                    _ => ':',
+                };
+                self.format_number_with(n, range_sep, false, &|v| v.to_string())
+            })
+            .join(",")
     }
 
-    /// Include a default end value
-    pub fn with_default_end(mut self, def: T) -> Self {
-        self.default_end = Some(def);
-        self
+    /// String representation for logs, if there are more than
+    /// `max_segments` numbers/ranges only the first and the last few
+    /// are shown with `…` in between.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1,3,5:8,10,12")?;
+    /// assert_eq!(rng.to_abbreviated_string(3), "1,3,…,12");
+    /// assert_eq!(rng.to_abbreviated_string(5), "1,3,5:8,10,12");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_abbreviated_string(&self, max_segments: usize) -> String {
+        let len = self.numbers.len();
+        if len <= max_segments {
+            return self.to_string();
+        }
+        let head = self.numbers.iter().take(max_segments.div_ceil(2));
+        let tail = self.numbers.iter().skip(len - max_segments / 2);
+        head.map(|n| self.format_number(n, false))
+            .chain(std::iter::once("…".to_string()))
+            .chain(tail.map(|n| self.format_number(n, false)))
+            .join(&self.options.list_sep.to_string())
     }
+}
 
-    /// Same as [`NumberRange::parse_str()`], Makes a
-    /// [`NumberRange<T>`] and parses the string.
-    pub fn parse<'a>(self, numstr: &'a str) -> Result<NumberRange<T>>
-    where
-        <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
-    {
-        let nr = NumberRange::from_options(self);
-        nr.parse_str(numstr)
+/// Inserts the separator between every `size` digits of the integer
+/// part of the number string.
+fn group_digits(num: &str, sep: char, size: usize) -> String {
+    let digits_start = num.find(|c: char| c.is_ascii_digit()).unwrap_or(num.len());
+    let digits_end = num[digits_start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(num.len(), |i| digits_start + i);
+    let digits = &num[digits_start..digits_end];
+    if size == 0 || digits.len() <= size {
+        return num.to_string();
+    }
+    let mut grouped = String::from(&num[..digits_start]);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(size) {
+            grouped.push(sep);
+        }
+        grouped.push(c);
     }
+    grouped.push_str(&num[digits_end..]);
+    grouped
 }
 
 impl<
         'a,
-        T: num::One
-            + std::str::FromStr
-            + num::One
-            + Copy
+        T: Copy
+            + std::ops::Add<Output = T>
+            + std::ops::Sub<Output = T>
             + std::cmp::PartialOrd
-            + std::ops::Add<Output = T>,
-    > Default for NumberRange<'a, T>
+            + num::Zero,
+    > Iterator for NumberRange<'a, T>
 {
-    /// It builds a NumberRange struct with
-    /// [`NumberRangeOptions::new()`] options.
-    fn default() -> Self {
-        Self {
-            numbers: VecDeque::new(),
-            original_repr: None,
-            options: NumberRangeOptions::default(),
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let first = self.numbers.pop_front()?;
+        match first.split_first() {
+            Some((value, rest)) => {
+                if let Some(rest) = rest {
+                    self.numbers.push_front(rest);
+                }
+                Some(value)
+            }
+            // people can insert their invalid ranges or parse invalid ones
+            None => self.next(),
         }
     }
 }
 
+/// Collected values of the [`NumberRange<T>`] that can be iterated
+/// multiple times, made with [`NumberRange::into_cached()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedRange<T> {
+    values: Vec<T>,
+}
+
+impl<T: Copy> CachedRange<T> {
+    /// Iterate through the values
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, T>> {
+        self.values.iter().copied()
+    }
+
+    /// Values as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+}
+
 impl<
         'a,
-        T: std::str::FromStr + num::One + Copy + std::cmp::PartialOrd + std::ops::Add<Output = T>,
+        T: Copy
+            + std::ops::Add<Output = T>
+            + std::ops::Sub<Output = T>
+            + std::cmp::PartialOrd
+            + num::Zero,
     > NumberRange<'a, T>
-where
-    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
 {
-    /// New NumberRange struct from NumberRangeOptions
-    pub fn from_options(options: NumberRangeOptions<T>) -> Self {
-        Self {
-            numbers: VecDeque::new(),
-            original_repr: None,
-            options,
+    /// Iterate through the values without consuming the numbers, so
+    /// it can be iterated multiple times.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,8")?;
+    /// assert_eq!(rng.iter().collect::<Vec<i64>>(), vec![1, 2, 3, 8]);
+    /// assert_eq!(rng.iter().sum::<i64>(), 14);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.numbers.iter().flat_map(Number::iter)
+    }
+
+    /// Iterate through the values as `f64`, like for plotting them
+    /// regardless of the number type. Integers larger than 2<sup>53</sup>
+    /// lose precision, they are rounded to the nearest `f64`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,-8")?;
+    /// assert_eq!(rng.iter_as_f64().collect::<Vec<f64>>(), vec![1.0, 2.0, 3.0, -8.0]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_as_f64(&self) -> impl Iterator<Item = f64> + '_
+    where
+        T: num::ToPrimitive,
+    {
+        self.iter().filter_map(|v| v.to_f64())
+    }
+
+    /// The value when there is only one number/range with only one
+    /// value, for the inputs that are just one number.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRange::<i64>::default().parse_str("5")?.as_single(), Some(5));
+    /// assert_eq!(NumberRange::<i64>::default().parse_str("5:5")?.as_single(), Some(5));
+    /// assert_eq!(NumberRange::<i64>::default().parse_str("1,2")?.as_single(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_single(&self) -> Option<T> {
+        if self.numbers.len() != 1 {
+            return None;
+        }
+        match self.numbers[0].split_first()? {
+            (value, None) => Some(value),
+            _ => None,
         }
     }
 
-    /// Get the Original String that was used to parse the iterator
-    pub fn original(&self) -> &str {
-        self.original_repr.unwrap_or("")
+    /// Aggregate over the numbers/ranges without iterating through
+    /// their values.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,Number};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,8,10:2:20")?;
+    /// let ends = rng.fold_segments(i64::MIN, |max, n| match *n {
+    ///     Number::Single(v) | Number::Repeat(v, _) => max.max(v),
+    ///     Number::Range(_, _, end) | Number::RevRange(_, _, end) => max.max(end),
+    ///     _ => max,
+    /// });
+    /// assert_eq!(ends, 20);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn fold_segments<A>(&self, init: A, f: impl Fn(A, &Number<T>) -> A) -> A {
+        self.numbers.iter().fold(init, f)
     }
 
-    /// Parse the human readable string (`numstr`).
+    /// Number of values that satisfy the predicate, without consuming
+    /// the numbers. It iterates through all the values, for the
+    /// multiples of a number use [`NumberRange::count_multiples_of()`].
     ///
-    /// Once parsed the NumberRange struct can be used as an
-    /// Iterator. Use `.collect::<T>()` to convert it into a vector.
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:100")?;
+    /// assert_eq!(rng.count_matching(|v| v % 2 == 0), 50);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn count_matching(&self, pred: impl Fn(T) -> bool) -> usize {
+        self.iter().filter(|v| pred(*v)).count()
+    }
+
+    /// Iterate through the numbers/ranges, each one as an iterator
+    /// through its own values.
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use number_range::NumberRange;
     /// #
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// NumberRange::<i64>::default().parse_str("1,3,5:10")?;
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,8")?;
+    /// let sums: Vec<i64> = rng.segment_iters().map(|seg| seg.sum()).collect();
+    /// assert_eq!(sums, vec![6, 8]);
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn parse_str(mut self, numstr: &'a str) -> Result<Self> {
-        self.original_repr = Some(numstr);
-        self.parse()
+    pub fn segment_iters(&self) -> impl Iterator<Item = impl Iterator<Item = T>> + '_ {
+        self.numbers.iter().map(Number::iter)
     }
 
-    pub fn from_vec<V>(self, nums: V, increment: Option<T>) -> Self
+    /// Iterate through the values that satisfy the predicate, use
+    /// [`NumberRange::iter()`] to filter without consuming it.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10")?;
+    /// assert_eq!(rng.iter().filter(|v| v % 3 == 0).collect::<Vec<i64>>(), vec![3, 6, 9]);
+    /// assert_eq!(rng.filter_values(|v| v % 2 == 0).collect::<Vec<i64>>(), vec![2, 4, 6, 8, 10]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn filter_values(self, pred: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = T> + 'a
     where
-        T: std::cmp::Ord,
-        V: IntoIterator<Item = T>,
+        T: 'a,
     {
-        let mut nums: Vec<T> = nums.into_iter().collect();
-        nums.sort();
-        self.from_vec_nosort(&nums, increment)
+        self.filter(pred)
     }
 
-    pub fn from_vec_nosort(mut self, nums: &[T], increment: Option<T>) -> Self
-    where
-        T: std::cmp::Ord,
-    {
-        self.original_repr = None;
-        let inc = increment.unwrap_or(num::one());
-        self.numbers.clear();
-        if nums.len() > 0 {
-            let mut first = &nums[0];
-            let mut prev = &nums[0];
-            let mut rng = false;
-            for current in &nums[1..] {
-                if current == prev {
-                    continue;
-                }
-                if *current == (*prev + inc) {
-                    if !rng {
-                        rng = true;
-                        first = prev;
-                    }
-                } else {
-                    if rng {
-                        self.numbers.push_back(Number::Range(*first, inc, *prev));
-                    } else {
-                        self.numbers.push_back(Number::Single(*prev));
-                    }
-                    rng = false;
-                }
-                prev = current;
-            }
-            if rng {
-                self.numbers.push_back(Number::Range(*first, inc, *prev));
-            } else {
-                self.numbers.push_back(Number::Single(*prev));
-            }
+    /// The start, step, end, and whether it is ascending, when the
+    /// numbers are a single range. For [`Number::RevRange`] the step
+    /// is its magnitude.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:2:9")?;
+    /// assert_eq!(rng.single_range_info(), Some((1, 2, 9, true)));
+    /// let rng = NumberRange::<i64>::default().parse_str("1:2:9,10")?;
+    /// assert_eq!(rng.single_range_info(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn single_range_info(&self) -> Option<(T, T, T, bool)> {
+        if self.numbers.len() != 1 {
+            return None;
+        }
+        match self.numbers[0] {
+            Number::Single(_) | Number::Repeat(..) => None,
+            Number::Range(start, step, end) => Some((start, step, end, step > T::zero())),
+            Number::RevRange(start, step, end) => Some((start, step, end, false)),
         }
-        self
     }
 
-    fn sanitize_number(&self, num: &str) -> String {
-        let num = num.trim().replace(self.options.group_sep, "");
-        let num = if self.options.whitespace {
-            num.split_whitespace().join("")
-        } else {
-            num
-        };
-        num.replace(self.options.decimal_sep, ".")
+    /// Iterate from the start of the single range, getting each next
+    /// value from the function until it goes past the end. Numbers
+    /// that aren't a single range don't give any values.
+    ///
+    /// The function must move the values towards the end, otherwise
+    /// the iterator will never stop.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:100")?;
+    /// assert_eq!(rng.iter_stepped_by(|x| x * 2 + 1).collect::<Vec<i64>>(),
+    ///            vec![1, 3, 7, 15, 31, 63]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_stepped_by(&self, f: impl Fn(T) -> T) -> impl Iterator<Item = T> {
+        let info = self.single_range_info();
+        let first = info.map(|(start, _, _, _)| start);
+        std::iter::successors(first, move |v| Some(f(*v))).take_while(move |v| match info {
+            Some((_, _, end, true)) => *v <= end,
+            Some((_, _, end, false)) => *v >= end,
+            None => false,
+        })
     }
 
-    fn parse_number(&self, num: &str, def: &Option<T>) -> Result<T> {
-        let s = self.sanitize_number(num);
-        if def.is_some() && s == "" {
-            return Ok(def.unwrap());
-        } else {
-            s.parse::<T>()
-                .with_context(|| format!("{} Not a Number", num))
-        }
+    /// Alternate between the values of the two ranges, once one of
+    /// them runs out the rest of the other one follows.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng1 = NumberRange::<i64>::default().parse_str("1:3")?;
+    /// let rng2 = NumberRange::<i64>::default().parse_str("10:13")?;
+    /// assert_eq!(rng1.interleave(rng2).collect::<Vec<i64>>(), vec![1, 10, 2, 11, 3, 12, 13]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interleave(self, other: NumberRange<'a, T>) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        Itertools::interleave(self, other)
     }
 
-    pub fn parse(mut self) -> Result<Self> {
-        if let Some(numstr) = self.original_repr {
-            if self.sanitize_number(numstr) == "" {
-                self.numbers.clear();
-                return Ok(self);
-            }
-            let numbers: VecDeque<Number<T>> = numstr
-                .split(self.options.list_sep)
-                .map(|seq_str| -> Result<Number<T>> {
-                    match seq_str.matches(self.options.range_sep).count() {
-                        0 => self.parse_number(seq_str, &None).map(|v| Number::Single(v)),
-                        1 => match seq_str.split_once(self.options.range_sep) {
-                            Some((start, end)) => {
-                                let start =
-                                    self.parse_number(start, &self.options.default_start)?;
-                                let end = self.parse_number(end, &self.options.default_end)?;
-                                Ok(Number::Range(start, num::One::one(), end))
-                            }
-                            None => panic!(
-                                "Checked there is single range_separator, yet split to 2 failed."
-                            ),
-                        },
-                        2 => {
-                            let nums: Vec<T> = seq_str
-                                .splitn(3, self.options.range_sep)
-                                .enumerate()
-                                .map(|(i, s)| -> Result<T> {
-                                    self.parse_number(
-                                        s,
-                                        [
-                                            &self.options.default_start,
-                                            &Some(num::One::one()),
-                                            &self.options.default_end,
-                                        ][i],
-                                    )
-                                })
-                                .collect::<Result<Vec<T>>>()?;
-                            Ok(Number::Range(nums[0], nums[1], nums[2]))
-                        }
-                        _ => Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into())
-                            .with_context(|| {
-                                format!(
-                                    "Too many range separators ({}) on {}",
-                                    self.options.range_sep, seq_str
-                                )
-                            }),
-                    }
-                })
-                .collect::<Result<VecDeque<Number<T>>>>()?;
-            self.numbers = numbers;
-            Ok(self)
-        } else {
-            Err::<NumberRange<'_, _>, anyhow::Error>(NumberRangeError {}.into())
-                .with_context(|| "Nothing to Parse".to_string())
+    /// Iterate through the values with a flag that is only true for
+    /// the last value. It only looks ahead to the next value.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,8")?;
+    /// assert_eq!(rng.iter_with_last().collect::<Vec<(i64, bool)>>(),
+    ///            vec![(1, false), (2, false), (3, false), (8, true)]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_with_last(self) -> impl Iterator<Item = (T, bool)> + 'a
+    where
+        T: 'a,
+    {
+        let mut iter = self.peekable();
+        std::iter::from_fn(move || {
+            let value = iter.next()?;
+            Some((value, iter.peek().is_none()))
+        })
+    }
+
+    /// Iterate through the values except the first and the last one.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:5")?;
+    /// assert_eq!(rng.interior().collect::<Vec<i64>>(), vec![2, 3, 4]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interior(self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.iter_with_last()
+            .skip(1)
+            .filter(|(_, last)| !last)
+            .map(|(v, _)| v)
+    }
+
+    /// Iterate through the running sum of the values.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:4")?;
+    /// assert_eq!(rng.cumulative().collect::<Vec<i64>>(), vec![1, 3, 6, 10]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn cumulative(self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.scan(T::zero(), |sum, v| {
+            *sum = *sum + v;
+            Some(*sum)
+        })
+    }
+
+    /// Numbers/ranges repeated `times` times, one after another.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3")?.repeat(2);
+    /// assert_eq!(format!("{}", rng), "1:3,1:3");
+    /// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 2, 3, 1, 2, 3]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn repeat(mut self, times: usize) -> Self {
+        let numbers = self.numbers.len();
+        self.numbers = self
+            .numbers
+            .iter()
+            .copied()
+            .cycle()
+            .take(numbers * times)
+            .collect();
+        self.original_repr = None;
+        self.padding_width = None;
+        self.parse_seps = None;
+        self
+    }
+
+    /// Call `f` with the index, the `total_hint` and the value for
+    /// each of the values, for things like progress bars. The
+    /// `total_hint` is the total the progress is shown against, for
+    /// integers it can be known beforehand from
+    /// [`NumberRange::total_count()`]. It's passed as it is, the index
+    /// can go past it when the hint is off.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,10")?;
+    /// let total = rng.total_count();
+    /// let mut done = Vec::new();
+    /// rng.for_each_with_progress(total, |i, n, v| done.push(format!("{}/{}: {}", i + 1, n, v)));
+    /// assert_eq!(done, vec!["1/4: 1", "2/4: 2", "3/4: 3", "4/4: 10"]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn for_each_with_progress(self, total_hint: usize, mut f: impl FnMut(usize, usize, T)) {
+        self.enumerate().for_each(|(i, v)| f(i, total_hint, v))
+    }
+
+    /// Collect the values once, so they can be iterated multiple
+    /// times from the [`CachedRange<T>`].
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let cached = NumberRange::<i64>::default().parse_str("1:3,8")?.into_cached();
+    /// assert_eq!(cached.iter().collect::<Vec<i64>>(), vec![1, 2, 3, 8]);
+    /// assert_eq!(cached.iter().max(), Some(8));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn into_cached(self) -> CachedRange<T> {
+        CachedRange {
+            values: self.collect(),
         }
     }
-}
 
-/// Macro rule for generating number range. The [`NumberRange<T>`] is
-/// made with default options, then parsed.
-///
-/// ```rust
-/// # use std::error::Error;
-/// # use number_range::numrng;
-/// #
-/// # fn main() -> Result<(), Box<dyn Error>> {
-/// let mut rng = numrng!(1,3:2:6,-4:-2);
-/// assert_eq!(format!("{}", rng), "1,3:2:6,-4:-2");
-/// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 5, -4, -3, -2]);
-/// assert_eq!(numrng!().collect::<Vec<i64>>(), vec![]);
-/// #     Ok(())
-/// # }
-/// ```
-#[macro_export]
-macro_rules! numrng {
-    ($($l:tt)*) => {
-	// stringify seems to introduce extra spaces between all characters
-        $crate::NumberRangeOptions::new().with_whitespace(true).parse(stringify!($($l)*)).unwrap()
-    };
+    /// Collect the values in ascending order, the values from the
+    /// overlapping numbers/ranges are kept as many times as they
+    /// come.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("5,1:3,2:4")?;
+    /// assert_eq!(rng.collect_sorted(), vec![1, 2, 2, 3, 3, 4, 5]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Use [`Vec::dedup()`] on the result if you want the values only
+    /// once.
+    pub fn collect_sorted(self) -> Vec<T>
+    where
+        T: std::cmp::Ord,
+    {
+        let mut values: Vec<T> = self.collect();
+        values.sort();
+        values
+    }
+
+    /// Iterate through the pairs of the consecutive values, like for
+    /// the differences between them.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:4")?;
+    /// assert_eq!(rng.pairwise().collect::<Vec<(i64, i64)>>(), vec![(1, 2), (2, 3), (3, 4)]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn pairwise(self) -> impl Iterator<Item = (T, T)> + 'a
+    where
+        T: 'a,
+    {
+        self.tuple_windows()
+    }
+
+    /// Iterate through the values with the ones below `low` as `low`
+    /// and the ones above `high` as `high`. Unlike
+    /// [`NumberRange::clamp_to_range()`] the values outside are kept.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("-2:2,8:12")?;
+    /// assert_eq!(
+    ///     rng.saturating_clamp(0, 10).collect::<Vec<i64>>(),
+    ///     vec![0, 0, 0, 1, 2, 8, 9, 10, 10, 10]
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn saturating_clamp(self, low: T, high: T) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.map(move |v| {
+            if v < low {
+                low
+            } else if v > high {
+                high
+            } else {
+                v
+            }
+        })
+    }
+
+    /// Iterate through the values, but the invalid ranges give an
+    /// error instead of being skipped silently.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,Number};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut rng = NumberRange::<i64>::default();
+    /// rng.numbers.push_back(Number::Range(4,1,2));
+    /// rng.numbers.push_back(Number::Single(1));
+    /// let mut iter = rng.iter_results();
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert_eq!(iter.next().unwrap()?, 1);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_results(mut self) -> impl Iterator<Item = Result<T>> + 'a
+    where
+        T: std::fmt::Display + num::One + 'a,
+    {
+        std::iter::from_fn(move || {
+            let first = self.numbers.pop_front()?;
+            match first.split_first() {
+                Some((value, rest)) => {
+                    if let Some(rest) = rest {
+                        self.numbers.push_front(rest);
+                    }
+                    Some(Ok(value))
+                }
+                None => Some(
+                    Err::<T, anyhow::Error>(NumberRangeError {}.into()).with_context(|| {
+                        format!("{} Invalid range", self.format_number(&first, true))
+                    }),
+                ),
+            }
+        })
+    }
 }
 
-/// Macro rule for generating number range into vec. The
-/// [`NumberRange<T>`] is made with default options, then parsed and
-/// collected into a Vec<T>.
-///
-/// ```rust
-/// # use std::error::Error;
-/// # use number_range::numvec;
-/// #
-/// # fn main() -> Result<(), Box<dyn Error>> {
-/// let mut rng: Vec<i64> = numvec!(1,3:2:6,-4:-2);
-/// assert_eq!(rng, vec![1, 3, 5, -4, -3, -2]);
-/// #     Ok(())
-/// # }
-/// ```
-#[macro_export]
-macro_rules! numvec {
-    ($($l:tt)*) => {
-        $crate::numrng!($($l)*)
-            .collect()
-    };
+impl<T: std::str::FromStr + num::Num + Copy + std::cmp::PartialOrd> Default
+    for NumberRangeOptions<T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-#[cfg(test)]
-use rstest::rstest;
+impl<T: std::str::FromStr + num::Num + Copy + std::cmp::PartialOrd> NumberRangeOptions<T> {
+    /// New struct with default options
+    pub fn new() -> Self {
+        Self {
+            list_sep: ',',
+            range_sep: ':',
+            decimal_sep: '.',
+            group_sep: '_',
+            group_size: 3,
+            validate_groups: false,
+            whitespace: false,
+            default_start: None,
+            default_end: None,
+            default_step: None,
+            percent_step: false,
+            math_intervals: false,
+            single_as_range: false,
+            range_sep_distinct: false,
+            max_segments: None,
+            max_segment_count: None,
+            keywords: false,
+            repeat_marker: None,
+            offset: None,
+            empty_on_equal_bounds: false,
+            exclusive_range_sep: None,
+            accounting_negatives: false,
+            decimals: true,
+            pm_marker: None,
+            count_delimiters: None,
+            descending: false,
+            autofix_direction: false,
+            target_count: None,
+            inclusivity_suffixes: false,
+            value_parser: None,
+        }
+    }
+
+    /// Change the group separator character
+    pub fn with_group_sep(mut self, sep: char) -> Self {
+        self.group_sep = sep;
+        self
+    }
+
+    /// Change the number of digits in a group
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let opts = || NumberRangeOptions::<u64>::new()
+    ///              .with_group_validation(true)
+    ///              .with_group_size(4);
+    /// assert_eq!(opts().parse("1234_5678")?.collect::<Vec<u64>>(), vec![12345678]);
+    /// assert!(opts().parse("123_45678").is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_group_size(mut self, size: usize) -> Self {
+        self.group_size = size;
+        self
+    }
+
+    /// Check the number of digits in the groups while parsing
+    pub fn with_group_validation(mut self, flag: bool) -> Self {
+        self.validate_groups = flag;
+        self
+    }
+
+    /// Change the group separator character
+    pub fn with_whitespace(mut self, flag: bool) -> Self {
+        self.whitespace = flag;
+        self
+    }
+
+    /// Change the decimal separator character
+    pub fn with_decimal_sep(mut self, sep: char) -> Self {
+        self.decimal_sep = sep;
+        self
+    }
+
+    /// Change the list separator character, a whitespace (e.g. `' '`)
+    /// splits the list on any run of whitespaces.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_list_sep(' ')
+    ///              .with_range_sep('-')
+    ///              .parse(" 1  3-5\t9")?.collect::<Vec<usize>>(), vec![1, 3, 4, 5, 9]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_list_sep(mut self, sep: char) -> Self {
+        self.list_sep = sep;
+        self
+    }
+
+    /// Change the range separator character
+    pub fn with_range_sep(mut self, sep: char) -> Self {
+        self.range_sep = sep;
+        self
+    }
+
+    /// Include a default start value
+    pub fn with_default_start(mut self, def: T) -> Self {
+        self.default_start = Some(def);
+        self
+    }
+
+    /// Include a default end value
+    pub fn with_default_end(mut self, def: T) -> Self {
+        self.default_end = Some(def);
+        self
+    }
+
+    /// Include both the default start and end values, for the bounds
+    /// of the data the ranges select from (e.g. the pages of a
+    /// document), so the open ended ranges go till them.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let opts = || NumberRangeOptions::<usize>::new().with_inferred_bounds(1, 5);
+    /// assert_eq!(opts().parse(":")?.collect::<Vec<usize>>(), vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(opts().parse("4:,:2")?.collect::<Vec<usize>>(), vec![4, 5, 1, 2]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_inferred_bounds(self, low: T, high: T) -> Self {
+        self.with_default_start(low).with_default_end(high)
+    }
+
+    /// Include a default step value
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_default_step(2)
+    ///              .parse("1::10,12:14")?.collect::<Vec<usize>>(), vec![1,3,5,7,9,12,13,14]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_default_step(mut self, def: T) -> Self {
+        self.default_step = Some(def);
+        self
+    }
+
+    /// Allow the step to be a percentage of the range span
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<f64>::new()
+    ///              .with_percent_step(true)
+    ///              .parse("0:25%:2")?.collect::<Vec<f64>>(), vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_percent_step(mut self, flag: bool) -> Self {
+        self.percent_step = flag;
+        self
+    }
+
+    /// Make the ranges without the step descending
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_descending(true)
+    ///              .parse("5:1,8")?.collect::<Vec<usize>>(), vec![5, 4, 3, 2, 1, 8]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_descending(mut self, flag: bool) -> Self {
+        self.descending = flag;
+        self
+    }
+
+    /// Swap the bounds of the ranges going in the wrong direction
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<i64>::new()
+    ///              .with_autofix_direction(true)
+    ///              .parse("5:1,1:-1:3")?.collect::<Vec<i64>>(), vec![1, 2, 3, 4, 5, 3, 2, 1]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_autofix_direction(mut self, flag: bool) -> Self {
+        self.autofix_direction = flag;
+        self
+    }
+
+    /// Number of values in the ranges with the `auto` step
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<i64>::new()
+    ///              .with_target_count(5)
+    ///              .parse("1:auto:9,40:auto:0")?.collect::<Vec<i64>>(),
+    ///            vec![1, 3, 5, 7, 9, 40, 30, 20, 10, 0]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_target_count(mut self, count: usize) -> Self {
+        self.target_count = Some(count);
+        self
+    }
+
+    /// Allow the number of values between the `open` and `close`
+    /// characters in place of the step
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<i64>::new()
+    ///              .with_count_delimiters('|', '|')
+    ///              .parse("1:|5|:9")?.collect::<Vec<i64>>(), vec![1, 3, 5, 7, 9]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_count_delimiters(mut self, open: char, close: char) -> Self {
+        self.count_delimiters = Some((open, close));
+        self
+    }
+
+    /// Allow the mathematical interval notations
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<i64>::new()
+    ///              .with_math_intervals(true)
+    ///              .parse("[1,5),8")?.collect::<Vec<i64>>(), vec![1, 2, 3, 4, 8]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_math_intervals(mut self, flag: bool) -> Self {
+        self.math_intervals = flag;
+        self
+    }
+
+    /// Treat the single numbers as the range till the default end
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_default_end(8)
+    ///              .with_single_as_range(true)
+    ///              .parse("5")?.collect::<Vec<usize>>(), vec![5, 6, 7, 8]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_single_as_range(mut self, flag: bool) -> Self {
+        self.single_as_range = flag;
+        self
+    }
+
+    /// Allow the range separator to be the same as the decimal
+    /// separator
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert!(NumberRangeOptions::<usize>::new()
+    ///              .with_range_sep('.')
+    ///              .parse("1.5").is_err());
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_range_sep('.')
+    ///              .with_range_sep_distinct(true)
+    ///              .parse("1.5")?.collect::<Vec<usize>>(), vec![1, 2, 3, 4, 5]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_range_sep_distinct(mut self, flag: bool) -> Self {
+        self.range_sep_distinct = flag;
+        self
+    }
+
+    /// Limit the number of list separated segments
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRangeOptions, ParseError};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let opts = || NumberRangeOptions::<usize>::new().with_max_segments(3);
+    /// assert_eq!(opts().parse("1,3:5,8")?.collect::<Vec<usize>>(), vec![1, 3, 4, 5, 8]);
+    /// let err = opts().parse("1,1,1,1").unwrap_err();
+    /// assert_eq!(err.downcast_ref::<ParseError>(), Some(&ParseError::TooManySegments));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_segments(mut self, max: usize) -> Self {
+        self.max_segments = Some(max);
+        self
+    }
+
+    /// Limit the number of values in each number/range
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let opts = || NumberRangeOptions::<usize>::new().with_max_segment_count(3);
+    /// assert_eq!(opts().parse("1:3,5:7,9")?.collect::<Vec<usize>>(), vec![1, 2, 3, 5, 6, 7, 9]);
+    /// assert!(opts().parse("1:1000").is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_segment_count(mut self, max: usize) -> Self {
+        self.max_segment_count = Some(max);
+        self
+    }
+
+    /// Parse `first` and `last` as the default start and end
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_default_start(1)
+    ///              .with_default_end(12)
+    ///              .with_keywords(true)
+    ///              .parse("first:3,10:last")?.collect::<Vec<usize>>(), vec![1, 2, 3, 10, 11, 12]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_keywords(mut self, flag: bool) -> Self {
+        self.keywords = flag;
+        self
+    }
+
+    /// Parse `countXvalue` as the value repeated count times, with
+    /// the given marker as `X`
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_repeat_marker('x')
+    ///              .parse("3x5,2x9")?.collect::<Vec<usize>>(), vec![5, 5, 5, 9, 9]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_repeat_marker(mut self, marker: char) -> Self {
+        self.repeat_marker = Some(marker);
+        self
+    }
+
+    /// Parse `center±radius` as the range from `center - radius` to
+    /// `center + radius`, with the given marker as `±`
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_pm_marker('±')
+    ///              .parse("10±2,20+-1")?.collect::<Vec<usize>>(), vec![8, 9, 10, 11, 12, 19, 20, 21]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_pm_marker(mut self, marker: char) -> Self {
+        self.pm_marker = Some(marker);
+        self
+    }
+
+    /// Add the offset to the parsed values
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<u16>::new()
+    ///              .with_offset(100)
+    ///              .parse("-5:2:5")?.collect::<Vec<u16>>(), vec![95, 97, 99, 101, 103, 105]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Make the ranges with the same start and end empty
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_empty_on_equal_bounds(true)
+    ///              .parse("1,5:2:5,7:7")?.collect::<Vec<usize>>(), vec![1]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_empty_on_equal_bounds(mut self, flag: bool) -> Self {
+        self.empty_on_equal_bounds = flag;
+        self
+    }
+
+    /// Change the separator for the ranges without the end
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_exclusive_range_sep(';')
+    ///              .parse("1;5,10:12")?.collect::<Vec<usize>>(), vec![1, 2, 3, 4, 10, 11, 12]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_exclusive_range_sep(mut self, sep: char) -> Self {
+        self.exclusive_range_sep = Some(sep);
+        self
+    }
+
+    /// Parse the `i`/`e` suffixes for the inclusive/exclusive ends
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<usize>::new()
+    ///              .with_inclusivity_suffixes(true)
+    ///              .parse("1:3i,7:10e,12:14")?.collect::<Vec<usize>>(),
+    ///            vec![1, 2, 3, 7, 8, 9, 12, 13, 14]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_inclusivity_suffixes(mut self, flag: bool) -> Self {
+        self.inclusivity_suffixes = flag;
+        self
+    }
+
+    /// Change the parser for the numbers
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// fn kilo(num: &str) -> anyhow::Result<u32> {
+    ///     match num.strip_suffix('k') {
+    ///         Some(n) => Ok(n.parse::<u32>()? * 1000),
+    ///         None => Ok(num.parse::<u32>()?),
+    ///     }
+    /// }
+    /// assert_eq!(NumberRangeOptions::<u32>::new()
+    ///              .with_value_parser(kilo)
+    ///              .parse("1k:500:2k,10")?.collect::<Vec<u32>>(),
+    ///            vec![1000, 1500, 2000, 10]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_value_parser(mut self, parser: fn(&str) -> Result<T>) -> Self {
+        self.value_parser = Some(parser);
+        self
+    }
+
+    /// Parse the numbers in parentheses as negative
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert_eq!(NumberRangeOptions::<i64>::new()
+    ///              .with_accounting_negatives(true)
+    ///              .parse("(3):0,(10)")?.collect::<Vec<i64>>(), vec![-3, -2, -1, 0, -10]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_accounting_negatives(mut self, flag: bool) -> Self {
+        self.accounting_negatives = flag;
+        self
+    }
+
+    /// Allow or reject the numbers with the decimal separator
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRangeOptions, ParseError};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let err = NumberRangeOptions::<f64>::new()
+    ///              .with_decimals(false)
+    ///              .parse("1:2.5").unwrap_err();
+    /// assert_eq!(
+    ///     err.downcast_ref::<ParseError>(),
+    ///     Some(&ParseError::FractionalForInteger { token: "2.5".to_string() })
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_decimals(mut self, flag: bool) -> Self {
+        self.decimals = flag;
+        self
+    }
+
+    /// Same as [`NumberRange::parse_str()`], Makes a
+    /// [`NumberRange<T>`] and parses the string.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn parse<'a>(self, numstr: &'a str) -> Result<NumberRange<'a, T>>
+    where
+        T: num::NumCast,
+        <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let nr = NumberRange::from_options(self);
+        nr.parse_str(numstr)
+    }
+
+    /// Parse the numbers/ranges of the string straight into a fixed
+    /// capacity vector, more than `N` numbers/ranges is an error.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{Number, NumberRangeOptions};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let numbers = NumberRangeOptions::<u8>::new().parse_into_array::<4>("1,3:5")?;
+    /// assert_eq!(numbers, [Number::Single(1), Number::Range(3, 1, 5)]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "heapless")]
+    pub fn parse_into_array<const N: usize>(
+        &self,
+        numstr: &str,
+    ) -> Result<heapless::Vec<Number<T>, N>>
+    where
+        T: num::NumCast,
+        <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.check_separators()?;
+        let mut numbers = heapless::Vec::new();
+        if self.sanitize_number(numstr).is_empty() {
+            return Ok(numbers);
+        }
+        for seq_str in self.split_segments(numstr)? {
+            let num = match self.parse_token(seq_str, None)? {
+                Some(num) => num,
+                None => continue,
+            };
+            if numbers.push(num).is_err() {
+                return Err::<_, anyhow::Error>(ParseError::TooManySegments.into())
+                    .with_context(|| format!("Only {} segments fit in the array", N));
+            }
+        }
+        Ok(numbers)
+    }
+}
+
+impl<T: std::str::FromStr + num::Num + num::NumCast + Copy + std::cmp::PartialOrd>
+    NumberRangeOptions<T>
+where
+    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    /// Check if the string can be parsed, without making the
+    /// [`NumberRange<T>`]. The error is the same as the one from
+    /// parsing.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let opts = NumberRangeOptions::<usize>::new();
+    /// assert!(opts.validate("1,3:10,14:2:20").is_ok());
+    /// assert!(opts.validate("1,3:-10").is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self, numstr: &str) -> Result<()> {
+        self.check_separators()?;
+        if self.sanitize_number(numstr).is_empty() {
+            return Ok(());
+        }
+        self.split_segments(numstr)?
+            .into_iter()
+            .try_for_each(|seq_str| self.parse_token(seq_str, None).map(|_| ()))
+    }
+
+    /// Checks the separators don't clash with each other.
+    fn check_separators(&self) -> Result<()> {
+        if self.range_sep == self.decimal_sep && !self.range_sep_distinct {
+            return Err::<(), anyhow::Error>(NumberRangeError {}.into()).with_context(|| {
+                format!(
+                    "Range separator and decimal separator are both ({}), \
+                     use with_range_sep_distinct(true) if that's intended",
+                    self.range_sep
+                )
+            });
+        }
+        Ok(())
+    }
+
+    fn sanitize_number(&self, num: &str) -> String {
+        // only the group separators are removed, the sign before a
+        // grouped number (e.g. `-1,000`) stays
+        let num = num.trim().replace(self.group_sep, "");
+        let num = if self.whitespace {
+            num.split_whitespace().join("")
+        } else {
+            num
+        };
+        let num = num.replace(self.decimal_sep, ".");
+        let num = match num.strip_prefix('(').and_then(|n| n.strip_suffix(')')) {
+            Some(inner) if self.accounting_negatives => format!("-{}", inner.trim()),
+            _ => num,
+        };
+        // explicit plus sign, not every number type parses it
+        match num.strip_prefix('+') {
+            Some(rest) if !rest.is_empty() && !rest.starts_with(['+', '-']) => rest.to_string(),
+            _ => num,
+        }
+    }
+
+    /// Width of the longest zero padded number (e.g. `007`) in the
+    /// number/range, the step is not counted.
+    fn padding_width(&self, seq_str: &str) -> Option<usize> {
+        let parts: Vec<&str> = seq_str.split(self.range_sep).collect();
+        let bounds = match parts[..] {
+            [start, _, end] => vec![start, end],
+            _ => parts,
+        };
+        bounds
+            .into_iter()
+            .filter_map(|num| {
+                let num = self.sanitize_number(num);
+                let digits = num.strip_prefix('-').unwrap_or(&num);
+                let padded = digits.len() > 1
+                    && digits.starts_with('0')
+                    && digits.chars().all(|c| c.is_ascii_digit());
+                padded.then_some(num.len())
+            })
+            .max()
+    }
+
+    /// Parses the start or end of a range, which must be finite. The
+    /// omitted one is the default value from the options.
+    fn parse_bound(&self, num: &str, side: BoundSide, base: Option<T>) -> Result<T> {
+        let def = match side {
+            BoundSide::Start => &self.default_start,
+            BoundSide::End => &self.default_end,
+        };
+        if def.is_none() && self.sanitize_number(num).is_empty() {
+            return Err::<T, anyhow::Error>(ParseError::MissingBound { side }.into())
+                .with_context(|| format!("{:?} Range {} is omitted", num, side));
+        }
+        let value = self.parse_value(num, def, base)?;
+        Self::check_finite(value, num)
+    }
+
+    /// Checks the bound is finite, `num` is how it was given.
+    fn check_finite(value: T, num: &str) -> Result<T> {
+        // only finite numbers give zero (NaN - NaN and inf - inf are NaN)
+        #[allow(clippy::eq_op)]
+        let finite = (value - value).is_zero();
+        if finite {
+            Ok(value)
+        } else {
+            Err::<T, anyhow::Error>(ParseError::NonFiniteBound.into())
+                .with_context(|| format!("{} Not a finite bound", num))
+        }
+    }
+
+    /// Checks the groups have `group_size` digits, except the first
+    /// one which can have fewer.
+    fn validate_groups(&self, num: &str) -> Result<()> {
+        let num = num.trim();
+        let num = if self.whitespace {
+            num.split_whitespace().join("")
+        } else {
+            num.to_string()
+        };
+        if !num.contains(self.group_sep) {
+            return Ok(());
+        }
+        let size = self.group_size;
+        let integer = num
+            .trim_start_matches(['+', '-', '('])
+            .trim_end_matches(')')
+            .split(self.decimal_sep)
+            .next()
+            .unwrap_or("");
+        let mut groups = integer.split(self.group_sep);
+        let first = groups.next().unwrap_or("").chars().count();
+        if (1..=size).contains(&first) && groups.all(|g| g.chars().count() == size) {
+            Ok(())
+        } else {
+            Err::<(), anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{} Not grouped by {} digits", num, size))
+        }
+    }
+
+    /// Checks if the number type can hold the fractions (e.g. `f64`),
+    /// the integer types can't parse `0.5`.
+    fn fractional() -> bool {
+        "0.5".parse::<T>().is_ok()
+    }
+
+    /// Sum that is `None` when it doesn't fit in the type, integers
+    /// are added as `i128` (or `u128` for the larger values).
+    fn checked_add(a: T, b: T) -> Option<T> {
+        if Self::fractional() {
+            return Some(a + b);
+        }
+        a.to_i128()
+            .zip(b.to_i128())
+            .and_then(|(a, b)| a.checked_add(b))
+            .and_then(T::from)
+            .or_else(|| T::from(a.to_u128()?.checked_add(b.to_u128()?)?))
+    }
+
+    /// Difference that is `None` when it doesn't fit in the type, like
+    /// [`NumberRangeOptions::checked_add()`].
+    fn checked_sub(a: T, b: T) -> Option<T> {
+        if Self::fractional() {
+            return Some(a - b);
+        }
+        a.to_i128()
+            .zip(b.to_i128())
+            .and_then(|(a, b)| a.checked_sub(b))
+            .and_then(T::from)
+            .or_else(|| T::from(a.to_u128()?.checked_sub(b.to_u128()?)?))
+    }
+
+    /// Distance between the integers as `u128`, which holds the span
+    /// of any primitive integer type without overflowing like the
+    /// difference in the type does.
+    fn int_span(a: T, b: T) -> Option<u128> {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        match (low.to_i128(), high.to_i128()) {
+            (Some(low), Some(high)) => Some(high.wrapping_sub(low) as u128),
+            // only the u128 values don't fit, they're not negative
+            _ => Some(high.to_u128()? - low.to_u128()?),
+        }
+    }
+
+    /// Greatest common divisor of the positive numbers.
+    fn gcd(a: T, b: T) -> T {
+        if b.is_zero() {
+            a
+        } else {
+            Self::gcd(b, a % b)
+        }
+    }
+
+    /// Checks the number doesn't have the decimal separator, when
+    /// the decimals are disabled.
+    fn check_decimals(&self, num: &str) -> Result<()> {
+        if self.decimals || !num.contains(self.decimal_sep) {
+            return Ok(());
+        }
+        let token = num.trim().to_string();
+        Err(ParseError::FractionalForInteger { token }.into())
+    }
+
+    fn parse_number(&self, num: &str, def: &Option<T>) -> Result<T> {
+        if self.keywords {
+            let keyword = match num.trim() {
+                "first" => Some((self.default_start, "default_start")),
+                "last" => Some((self.default_end, "default_end")),
+                _ => None,
+            };
+            match keyword {
+                Some((Some(value), _)) => return Ok(value),
+                Some((None, option)) => {
+                    return Err::<T, anyhow::Error>(NumberRangeError {}.into()).with_context(|| {
+                        format!("{} Keyword needs the {} option", num.trim(), option)
+                    })
+                }
+                None => (),
+            }
+        }
+        if self.validate_groups {
+            self.validate_groups(num)?;
+        }
+        self.check_decimals(num)?;
+        let s = self.sanitize_number(num);
+        match def {
+            Some(d) if s.is_empty() => Ok(*d),
+            _ if self.value_parser.is_some() => self.parse_sanitized(&s),
+            _ => s.parse::<T>().or_else(|err| {
+                if !Self::fractional() && s.contains('.') && s.replace('.', "").parse::<T>().is_ok()
+                {
+                    let token = num.trim().to_string();
+                    Err(ParseError::FractionalForInteger { token }.into())
+                } else {
+                    Err(anyhow::Error::from(err)).with_context(|| format!("{} Not a Number", num))
+                }
+            }),
+        }
+    }
+
+    /// Parses the sanitized number with the `value_parser`, or as `T`
+    /// when there isn't one.
+    fn parse_sanitized(&self, s: &str) -> Result<T> {
+        match self.value_parser {
+            Some(parser) => parser(s).with_context(|| format!("{} Not a Number", s)),
+            None => s
+                .parse::<T>()
+                .with_context(|| format!("{} Not a Number", s)),
+        }
+    }
+
+    /// Parses the number that is a value (not a step), which is
+    /// moved by the `offset` if there's one.
+    fn parse_value(&self, num: &str, def: &Option<T>, base: Option<T>) -> Result<T> {
+        if let Some(base) = base {
+            if let Some(value) = self.parse_delta(num, base) {
+                return value;
+            }
+        }
+        let offset = match self.offset {
+            Some(offset) => offset,
+            None => return self.parse_number(num, def),
+        };
+        let s = self.sanitize_number(num);
+        if s.is_empty() || (self.keywords && (s == "first" || s == "last")) {
+            return self.parse_number(num, def);
+        }
+        if self.validate_groups {
+            self.validate_groups(num)?;
+        }
+        self.check_decimals(num)?;
+        let offset_wide = i128::from(offset);
+        let shifted = match self.parse_sanitized(&s) {
+            Ok(value) if Self::fractional() => T::from(offset).map(|off| value + off),
+            Ok(value) => match value.to_i128() {
+                Some(v) => v.checked_add(offset_wide).and_then(T::from),
+                // only the u128 values don't fit
+                None => value
+                    .to_u128()
+                    .and_then(|v| v.checked_add_signed(offset_wide))
+                    .and_then(T::from),
+            },
+            // like the negative numbers for the unsigned types
+            Err(err) => match s.parse::<i128>() {
+                Ok(v) => v.checked_add(offset_wide).and_then(T::from),
+                Err(_) => return Err(err),
+            },
+        };
+        shifted.with_context(|| format!("{} Out of range with the offset {}", num, offset))
+    }
+
+    /// Parses the value with the explicit sign relative to the
+    /// `base`, `None` if it doesn't have the sign.
+    fn parse_delta(&self, num: &str, base: T) -> Option<Result<T>> {
+        let num = num.trim();
+        let (before, delta) = match num.strip_prefix('+') {
+            Some(delta) => (false, delta),
+            None => (true, num.strip_prefix('-')?),
+        };
+        let value = self.parse_number(delta, &None).and_then(|delta| {
+            let value = if before {
+                Self::checked_sub(base, delta)
+            } else {
+                Self::checked_add(base, delta)
+            };
+            value.with_context(|| format!("{} Out of range from the base", num))
+        });
+        Some(value)
+    }
+
+    /// Parses the step of the range, if the step is negative but the
+    /// number type can't hold it (unsigned) then it's parsed as a
+    /// [`SignedStep`] with its magnitude. The step can't be zero.
+    fn parse_step(&self, start: T, step: &str, end: T) -> Result<Number<T>> {
+        if self.percent_step {
+            if let Some(percent) = self.sanitize_number(step).strip_suffix('%') {
+                return self.percent_step(start, percent, end);
+            }
+        }
+        if let Some((open, close)) = self.count_delimiters {
+            let count = step
+                .trim()
+                .strip_prefix(open)
+                .and_then(|c| c.strip_suffix(close));
+            if let Some(count) = count {
+                let count = count
+                    .trim()
+                    .parse::<usize>()
+                    .with_context(|| format!("{} Not a valid count", count))?;
+                return self.count_step(start, count, end);
+            }
+        }
+        if step.trim() == "auto" {
+            return match self.target_count {
+                Some(count) => self.count_step(start, count, end),
+                None => Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into())
+                    .with_context(|| "auto Step needs the target_count option".to_string()),
+            };
+        }
+        if self.descending && self.default_step.is_none() && step.trim().is_empty() {
+            return Ok(Number::RevRange(start, T::one(), end));
+        }
+        let def = self.default_step.unwrap_or(num::One::one());
+        let num = match self.parse_number(step, &Some(def)) {
+            Ok(step) => Number::Range(start, step, end),
+            Err(err) if err.downcast_ref::<ParseError>().is_some() => return Err(err),
+            Err(err) => match self.sanitize_number(step).strip_prefix('-') {
+                Some(mag) => self
+                    .parse_sanitized(mag)
+                    .map(|mag| Number::with_signed_step(start, SignedStep::new(mag, true), end))
+                    .with_context(|| format!("{} Not a Number", step))?,
+                None => return Err(err),
+            },
+        };
+        Self::check_step(num, step)
+    }
+
+    /// Checks the step of the range isn't zero or NaN, `step` is how
+    /// it was given.
+    fn check_step(num: Number<T>, step: &str) -> Result<Number<T>> {
+        match num {
+            Number::Range(_, s, _) | Number::RevRange(_, s, _) if s.is_zero() => {
+                Err::<Number<_>, anyhow::Error>(ParseError::ZeroStep.into())
+                    .with_context(|| format!("{} Step can't be zero", step))
+            }
+            // only NaN isn't equal to itself
+            #[allow(clippy::eq_op)]
+            Number::Range(_, s, _) | Number::RevRange(_, s, _) if s != s => {
+                Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into())
+                    .with_context(|| format!("{} Step is not a number", step))
+            }
+            num => Ok(num),
+        }
+    }
+
+    /// Makes the range with the step from the percentage of the span,
+    /// descending spans make a [`Number::RevRange`].
+    fn percent_step(&self, start: T, percent_str: &str, end: T) -> Result<Number<T>> {
+        let percent = percent_str
+            .parse::<T>()
+            .with_context(|| format!("{}% Not a Percentage", percent_str))?;
+        if percent.is_zero() {
+            return Err::<Number<_>, anyhow::Error>(ParseError::ZeroStep.into())
+                .with_context(|| format!("{}% Step can't be zero", percent_str));
+        }
+        // only NaN isn't equal to itself
+        #[allow(clippy::eq_op)]
+        if percent < T::zero() || percent != percent {
+            return Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{}% Not a valid percentage", percent_str));
+        }
+        let hundred = "100"
+            .parse::<T>()
+            .with_context(|| "Number type can't hold 100 for percentage")?;
+        if start == end {
+            return Ok(Number::Single(start));
+        }
+        let step = if Self::fractional() {
+            let span = if start <= end {
+                end - start
+            } else {
+                start - end
+            };
+            span * percent / hundred
+        } else {
+            // with the reduced fraction the span is divided first, so
+            // the step is never larger than the span it came from
+            let divisor = Self::gcd(percent, hundred);
+            let (num, den) = (percent / divisor, hundred / divisor);
+            // the span (as u128) can be larger than the type, the
+            // step has to fit in it
+            let (span, num, den) = Self::int_span(start, end)
+                .zip(num.to_u128())
+                .zip(den.to_u128())
+                .map(|((span, num), den)| (span, num, den))
+                .with_context(|| format!("{}% Span doesn't fit for the step", percent_str))?;
+            if span % den != 0 {
+                return Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into()).with_context(
+                    || format!("{}% of the span doesn't give an exact step", percent_str),
+                );
+            }
+            if num > den {
+                // steps past the end only have the start
+                return Ok(Number::Single(start));
+            }
+            T::from(span / den * num).with_context(|| {
+                format!(
+                    "{}% of the span doesn't fit in the number type",
+                    percent_str
+                )
+            })?
+        };
+        if start <= end {
+            Ok(Number::Range(start, step, end))
+        } else {
+            Ok(Number::RevRange(start, step, end))
+        }
+    }
+
+    /// Makes the range with the step that gives `count` number of
+    /// values, descending spans make a [`Number::RevRange`].
+    fn count_step(&self, start: T, count: usize, end: T) -> Result<Number<T>> {
+        if count == 1 && start == end {
+            return Ok(Number::Single(start));
+        }
+        if count < 2 || start == end {
+            return Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{} values don't fit between the bounds", count));
+        }
+        let step = if Self::fractional() {
+            let span = if start <= end {
+                end - start
+            } else {
+                start - end
+            };
+            let intervals = (count - 1)
+                .to_string()
+                .parse::<T>()
+                .with_context(|| format!("{} Count too large for the number type", count))?;
+            span / intervals
+        } else {
+            // the span (as u128) can be larger than the type, the
+            // step has to fit in it
+            let span = Self::int_span(start, end)
+                .with_context(|| format!("{} Span doesn't fit for the step", count))?;
+            let intervals = (count - 1) as u128;
+            if span % intervals != 0 {
+                return Err::<Number<_>, anyhow::Error>(NumberRangeError {}.into())
+                    .with_context(|| format!("{} values don't give an exact step", count));
+            }
+            T::from(span / intervals).with_context(|| {
+                format!("{} values give a step too large for the number type", count)
+            })?
+        };
+        if start <= end {
+            Ok(Number::Range(start, step, end))
+        } else {
+            Ok(Number::RevRange(start, step, end))
+        }
+    }
+
+    /// Split the string by the list separator, and check there
+    /// aren't more segments than `max_segments`.
+    fn split_segments<'s>(&self, numstr: &'s str) -> Result<Vec<&'s str>> {
+        let segments = self.split_list(numstr);
+        match self.max_segments {
+            Some(max) if segments.len() > max => {
+                Err::<_, anyhow::Error>(ParseError::TooManySegments.into()).with_context(|| {
+                    format!("{} segments given, at most {} allowed", segments.len(), max)
+                })
+            }
+            _ => Ok(segments),
+        }
+    }
+
+    /// Split the string by the list separator, the separators inside
+    /// the math intervals (e.g. `[1,5)`) don't split it. Whitespace
+    /// list separator splits on any run of whitespaces.
+    fn split_list<'s>(&self, numstr: &'s str) -> Vec<&'s str> {
+        if !self.math_intervals {
+            if self.list_sep.is_whitespace() {
+                return numstr.split_whitespace().collect();
+            }
+            return numstr.split(self.list_sep).collect();
+        }
+        let mut tokens = Vec::new();
+        let mut depth: usize = 0;
+        let mut last = 0;
+        for (i, c) in numstr.char_indices() {
+            match c {
+                '[' | '(' => depth += 1,
+                ']' | ')' => depth = depth.saturating_sub(1),
+                c if c == self.list_sep && depth == 0 => {
+                    tokens.push(&numstr[last..i]);
+                    last = i + c.len_utf8();
+                }
+                _ => (),
+            }
+        }
+        tokens.push(&numstr[last..]);
+        tokens
+    }
+
+    /// Parses the math interval (e.g. `[1,5)`), the exclusive ends
+    /// are moved by one towards the other end, so they're only for
+    /// the integer types. `None` inside if the interval is empty.
+    fn parse_interval(&self, seq_str: &str, base: Option<T>) -> Option<Result<Option<Number<T>>>> {
+        let seq_str = seq_str.trim();
+        let inner = seq_str.strip_prefix(['[', '('])?.strip_suffix([']', ')'])?;
+        let parse = || -> Result<Option<Number<T>>> {
+            let (start, end) = inner
+                .split_once(self.list_sep)
+                .with_context(|| format!("{} Interval needs two bounds", seq_str))?;
+            let mut start = self.parse_bound(start, BoundSide::Start, base)?;
+            let mut end = self.parse_bound(end, BoundSide::End, base)?;
+            let (open_start, open_end) = (seq_str.starts_with('('), seq_str.ends_with(')'));
+            if (open_start || open_end) && Self::fractional() {
+                return Err::<_, anyhow::Error>(NumberRangeError {}.into())
+                    .with_context(|| format!("{} Exclusive bounds need an integer type", seq_str));
+            }
+            if open_start {
+                if start >= end {
+                    return Ok(None);
+                }
+                start = start + T::one();
+            }
+            if open_end {
+                if end <= start {
+                    return Ok(None);
+                }
+                end = end - T::one();
+            }
+            Ok(Some(Number::Range(start, T::one(), end)))
+        };
+        Some(parse())
+    }
+
+    /// Makes the single number, or the range to the default end (or
+    /// from the default start) when `single_as_range` is set.
+    fn single(&self, value: T) -> Number<T> {
+        if self.single_as_range {
+            if let Some(end) = self.default_end {
+                return Number::Range(value, T::one(), end);
+            } else if let Some(start) = self.default_start {
+                return Number::Range(start, T::one(), value);
+            }
+        }
+        Number::Single(value)
+    }
+
+    /// Parses the value repeated count times (e.g. `3x5`).
+    fn parse_repeat(&self, count: &str, value: &str, base: Option<T>) -> Result<Number<T>> {
+        let count = count
+            .trim()
+            .parse::<usize>()
+            .with_context(|| format!("{} Not a valid count", count))?;
+        let value = self.parse_value(value, &None, base)?;
+        Ok(Number::Repeat(value, count))
+    }
+
+    /// Parses the range around the center (e.g. `100±5`), the
+    /// radius can't be negative or go past the bounds of the type.
+    fn parse_pm(&self, center: &str, radius: &str, base: Option<T>) -> Result<Option<Number<T>>> {
+        let seq_str = || format!("{}±{}", center.trim(), radius.trim());
+        let center = self.parse_value(center, &None, base)?;
+        let radius = self.parse_number(radius, &None)?;
+        if radius < T::zero() {
+            return Err::<_, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{} Radius can't be negative", seq_str()));
+        }
+        match (
+            Self::checked_sub(center, radius),
+            Self::checked_add(center, radius),
+        ) {
+            (Some(start), Some(end)) => Ok(self.equal_bounds(Number::Range(start, T::one(), end))),
+            _ => Err::<_, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{} Range doesn't fit in the type", seq_str())),
+        }
+    }
+
+    /// Leaves out the range with the same start and end (`None`), if
+    /// `empty_on_equal_bounds` is set.
+    fn equal_bounds(&self, num: Number<T>) -> Option<Number<T>> {
+        match num {
+            Number::Range(start, _, end) | Number::RevRange(start, _, end)
+                if self.empty_on_equal_bounds && start == end =>
+            {
+                None
+            }
+            num => Some(num),
+        }
+    }
+
+    /// Parses a single number or range from the list, and checks it
+    /// doesn't have more than `max_segment_count` values. `None` if
+    /// it has no values (e.g. `[1,1)`), so it's left out.
+    fn parse_token(&self, seq_str: &str, base: Option<T>) -> Result<Option<Number<T>>> {
+        let num = self.parse_segment(seq_str, base)?;
+        self.check_count(num, seq_str)
+    }
+
+    /// Checks the number/range doesn't have more than
+    /// `max_segment_count` values, `seq_str` is how it was given.
+    fn check_count(&self, num: Option<Number<T>>, seq_str: &str) -> Result<Option<Number<T>>> {
+        match (self.max_segment_count, &num) {
+            (Some(max), Some(num)) if Self::exceeds_count(num, max) => {
+                Err::<_, anyhow::Error>(NumberRangeError {}.into())
+                    .with_context(|| format!("{} Has more than {} values", seq_str.trim(), max))
+            }
+            _ => Ok(num),
+        }
+    }
+
+    /// Checks if the number/range has more than `max` values, the
+    /// limit that doesn't fit in the type can't be exceeded.
+    fn exceeds_count(num: &Number<T>, max: usize) -> bool {
+        if num.is_invalid() {
+            return false;
+        }
+        let (start, step, end) = match *num {
+            Number::Single(_) => return max == 0,
+            Number::Repeat(_, count) => return count > max,
+            Number::Range(start, step, end) | Number::RevRange(start, step, end) => {
+                (start, step, end)
+            }
+        };
+        // values are one more than the number of steps
+        if Self::fractional() {
+            let max_steps = match T::from(max) {
+                Some(max) => max,
+                None => return false,
+            };
+            let span = if start <= end {
+                end - start
+            } else {
+                start - end
+            };
+            let step = if step < T::zero() {
+                T::zero() - step
+            } else {
+                step
+            };
+            return span / step >= max_steps;
+        }
+        match (Self::int_span(start, end), Self::int_span(step, T::zero())) {
+            (Some(span), Some(step)) => span / step >= max as u128,
+            _ => false,
+        }
+    }
+
+    /// Parses a single number or range from the list, `None` if it
+    /// has no values.
+    fn parse_segment(&self, seq_str: &str, base: Option<T>) -> Result<Option<Number<T>>> {
+        if self.math_intervals {
+            if let Some(interval) = self.parse_interval(seq_str, base) {
+                return interval;
+            }
+        }
+        if let Some(marker) = self.repeat_marker {
+            if let Some((count, value)) = seq_str.split_once(marker) {
+                return self.parse_repeat(count, value, base).map(Some);
+            }
+        }
+        if let Some(marker) = self.pm_marker {
+            let pm = seq_str
+                .split_once(marker)
+                .or_else(|| seq_str.split_once("+-"));
+            if let Some((center, radius)) = pm {
+                return self.parse_pm(center, radius, base);
+            }
+        }
+        if self.inclusivity_suffixes {
+            if let Some(num) = self.parse_suffixed(seq_str, base) {
+                return num;
+            }
+        }
+        if let Some(sep) = self.exclusive_range_sep {
+            if seq_str.contains(sep) {
+                return self
+                    .parse_range(seq_str, sep, base)
+                    .map(|n| n.and_then(|n| self.exclude_end(n)));
+            }
+        }
+        self.parse_range(seq_str, self.range_sep, base)
+    }
+
+    /// Parses the range with the `i` (inclusive) or `e` (exclusive)
+    /// suffix, the single numbers don't take a suffix. The exclusive
+    /// end is left out like with the `exclusive_range_sep`.
+    fn parse_suffixed(&self, seq_str: &str, base: Option<T>) -> Option<Result<Option<Number<T>>>> {
+        let seq_str = seq_str.trim();
+        let (range, exclusive) = match seq_str.strip_suffix('i') {
+            Some(range) => (range, false),
+            None => (seq_str.strip_suffix('e')?, true),
+        };
+        let sep = match self.exclusive_range_sep {
+            Some(sep) if range.contains(sep) => sep,
+            _ if range.contains(self.range_sep) => self.range_sep,
+            _ => return None,
+        };
+        let num = self.parse_range(range, sep, base);
+        Some(if exclusive {
+            num.map(|n| n.and_then(|n| self.exclude_end(n)))
+        } else {
+            num
+        })
+    }
+
+    /// Swaps the start and end of the range going in the other
+    /// direction than its step, if `autofix_direction` is set.
+    fn autofix_direction(&self, num: Number<T>) -> Number<T> {
+        if !self.autofix_direction || num.is_valid() {
+            return num;
+        }
+        match num {
+            Number::Range(start, step, end) => Number::Range(end, step, start),
+            Number::RevRange(start, step, end) => Number::RevRange(end, step, start),
+            num => num,
+        }
+    }
+
+    /// Leaves out the end value of the range when the steps land on
+    /// it, by moving the end back a step. `None` if that was its only
+    /// value.
+    fn exclude_end(&self, num: Number<T>) -> Option<Number<T>> {
+        // same remainders without the span, which can overflow, they
+        // differ by a step when the bounds have different signs
+        let lands = |start: T, step: T, end: T| {
+            let (a, b) = (end % step, start % step);
+            a == b || ((a < T::zero()) != (b < T::zero()) && ((a - b) % step).is_zero())
+        };
+        match num {
+            Number::Range(start, _, end) | Number::RevRange(start, _, end) if start == end => None,
+            Number::Range(start, step, end)
+                if (start < end) == (step > T::zero()) && lands(start, step, end) =>
+            {
+                Some(Number::Range(start, step, end - step))
+            }
+            Number::RevRange(start, step, end) if start > end && lands(start, step, end) => {
+                Some(Number::RevRange(start, step, end + step))
+            }
+            num => Some(num),
+        }
+    }
+
+    /// Parses a single number or range separated by `range_sep`,
+    /// `None` if it's left out for having no values.
+    fn parse_range(
+        &self,
+        seq_str: &str,
+        range_sep: char,
+        base: Option<T>,
+    ) -> Result<Option<Number<T>>> {
+        match seq_str.matches(range_sep).count() {
+            0 => self
+                .parse_value(seq_str, &None, base)
+                .map(|v| Some(self.single(v))),
+            1 => match seq_str.split_once(range_sep) {
+                Some((start, end)) => {
+                    let start = self.parse_bound(start, BoundSide::Start, base)?;
+                    let end = self.parse_bound(end, BoundSide::End, base)?;
+                    let num = if self.descending {
+                        Number::RevRange(start, T::one(), end)
+                    } else {
+                        Number::Range(start, T::one(), end)
+                    };
+                    Ok(self.equal_bounds(self.autofix_direction(num)))
+                }
+                None => panic!("Checked there is single range_separator, yet split to 2 failed."),
+            },
+            2 => {
+                let nums: Vec<&str> = seq_str.splitn(3, range_sep).collect();
+                let start = self.parse_bound(nums[0], BoundSide::Start, base)?;
+                let end = self.parse_bound(nums[2], BoundSide::End, base)?;
+                self.parse_step(start, nums[1], end)
+                    .map(|n| self.equal_bounds(self.autofix_direction(n)))
+            }
+            _ => Err::<_, anyhow::Error>(NumberRangeError {}.into()).with_context(|| {
+                format!("Too many range separators ({}) on {}", range_sep, seq_str)
+            }),
+        }
+    }
+}
+
+impl<'a, T: std::str::FromStr + num::Num + Copy + std::cmp::PartialOrd> Default
+    for NumberRange<'a, T>
+{
+    /// It builds a NumberRange struct with
+    /// [`NumberRangeOptions::new()`] options.
+    fn default() -> Self {
+        Self {
+            numbers: VecDeque::new(),
+            original_repr: None,
+            padding_width: None,
+            parse_seps: None,
+            relative_base: None,
+            options: NumberRangeOptions::default(),
+        }
+    }
+}
+
+impl<'a, T: std::str::FromStr + num::Num + num::NumCast + Copy + std::cmp::PartialOrd>
+    NumberRange<'a, T>
+where
+    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    /// New NumberRange struct from NumberRangeOptions
+    pub fn from_options(options: NumberRangeOptions<T>) -> Self {
+        Self {
+            numbers: VecDeque::new(),
+            original_repr: None,
+            padding_width: None,
+            parse_seps: None,
+            relative_base: None,
+            options,
+        }
+    }
+
+    /// Get the Original String that was used to parse the iterator
+    pub fn original(&self) -> &str {
+        self.original_repr.as_deref().unwrap_or("")
+    }
+
+    /// Original list separated tokens of the parsed string, they are
+    /// trimmed of whitespaces if `trim` is true.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1, 3:10 , 14")?;
+    /// assert_eq!(rng.tokens(false), vec!["1", " 3:10 ", " 14"]);
+    /// assert_eq!(rng.tokens(true), vec!["1", "3:10", "14"]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn tokens(&self, trim: bool) -> Vec<&str> {
+        let numstr = self.original();
+        if self.options.sanitize_number(numstr).is_empty() {
+            return Vec::new();
+        }
+        let tokens = self.options.split_list(numstr).into_iter();
+        if trim {
+            tokens.map(str::trim).collect()
+        } else {
+            tokens.collect()
+        }
+    }
+
+    /// Width of the widest zero padded number (e.g. `"007"`) in the
+    /// parsed string, `None` if none of the numbers were padded.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("001:010,5")?;
+    /// assert_eq!(rng.detected_width(), Some(3));
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10")?;
+    /// assert_eq!(rng.detected_width(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn detected_width(&self) -> Option<usize> {
+        self.padding_width
+    }
+
+    /// Remove the invalid numbers/ranges, that'd be skipped while
+    /// iterating, and return how many were removed.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,Number};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut rng = NumberRange::<i64>::default().parse_str("1,5:2,3:4")?;
+    /// assert_eq!(rng.sanitize_segments(), 1);
+    /// assert_eq!(format!("{}", rng), "1,3:4");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sanitize_segments(&mut self) -> usize {
+        let before = self.numbers.len();
+        self.numbers.retain(Number::is_valid);
+        before - self.numbers.len()
+    }
+
+    /// Add the numbers/ranges of `other` after these ones, the
+    /// values in both are kept twice (unlike the set union).
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut rng = NumberRange::<i64>::default().parse_str("1:3")?;
+    /// rng.append_all(&NumberRange::default().parse_str("2,5")?);
+    /// assert_eq!(format!("{}", rng), "1:3,2,5");
+    /// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 2, 3, 2, 5]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn append_all(&mut self, other: &NumberRange<T>) {
+        self.numbers.extend(other.numbers.iter().copied());
+        self.original_repr = None;
+        self.padding_width = None;
+        self.parse_seps = None;
+    }
+
+    /// Parse the human readable string (`numstr`).
+    ///
+    /// Once parsed the NumberRange struct can be used as an
+    /// Iterator. Use `.collect::<T>()` to convert it into a vector.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// NumberRange::<i64>::default().parse_str("1,3,5:10")?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(mut self, numstr: &'a str) -> Result<Self> {
+        self.original_repr = Some(Cow::Borrowed(numstr));
+        self.relative_base = None;
+        self.parse()
+    }
+
+    /// Parse the human readable string (`numstr`) with the numbers
+    /// that have the explicit sign relative to the `base`, `"+n"` is
+    /// `base + n` and `"-n"` is `base - n`. The steps are not
+    /// relative. The base is kept, so [`NumberRange::parse()`] parses
+    /// the string again with it.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<usize>::default().parse_relative("-2:+2,20", 10)?;
+    /// assert_eq!(rng.collect::<Vec<usize>>(), vec![8, 9, 10, 11, 12, 20]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_relative(mut self, numstr: &'a str, base: T) -> Result<Self> {
+        self.original_repr = Some(Cow::Borrowed(numstr));
+        self.relative_base = Some(base);
+        self.parse()
+    }
+
+    /// Parse the human readable string (`numstr`) into the
+    /// [`OwnedNumberRange<T>`] that keeps a copy of the string, so it
+    /// doesn't borrow the input.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,OwnedNumberRange};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng: OwnedNumberRange<i64> = {
+    ///     let input = String::from("1,3:5");
+    ///     NumberRange::default().parse_owned(&input)?
+    /// };
+    /// assert_eq!(rng.original(), "1,3:5");
+    /// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 4, 5]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_owned(self, numstr: &str) -> Result<OwnedNumberRange<T>> {
+        NumberRange {
+            numbers: self.numbers,
+            original_repr: Some(Cow::Owned(numstr.to_string())),
+            padding_width: None,
+            parse_seps: None,
+            relative_base: None,
+            options: self.options,
+        }
+        .parse()
+    }
+
+    /// Parse as much of the human readable string as possible, the
+    /// numbers/ranges that fail to parse are skipped and their errors
+    /// are returned alongside.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (rng, errors) = NumberRange::<i64>::default().parse_partial("1,bad,3:5");
+    /// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 4, 5]);
+    /// assert_eq!(errors.len(), 1);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_partial(mut self, numstr: &'a str) -> (Self, Vec<anyhow::Error>) {
+        self.original_repr = Some(Cow::Borrowed(numstr));
+        self.relative_base = None;
+        self.padding_width = None;
+        self.parse_seps = Some((self.options.list_sep, self.options.range_sep));
+        self.numbers.clear();
+        let mut errors = Vec::new();
+        if let Err(err) = self.options.check_separators() {
+            errors.push(err);
+            return (self, errors);
+        }
+        if self.options.sanitize_number(numstr).is_empty() {
+            return (self, errors);
+        }
+        let segments = match self.options.split_segments(numstr) {
+            Ok(segments) => segments,
+            Err(err) => {
+                errors.push(err);
+                return (self, errors);
+            }
+        };
+        for seq_str in segments {
+            match self.options.parse_token(seq_str, self.relative_base) {
+                Ok(num) => {
+                    self.numbers.extend(num);
+                    self.padding_width =
+                        self.padding_width.max(self.options.padding_width(seq_str));
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+        (self, errors)
+    }
+
+    /// Parse the human readable string after the required prefix,
+    /// for inputs like `"pages=1-5,10"`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::{NumberRange,NumberRangeOptions};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<usize>::from_options(
+    ///              NumberRangeOptions::new().with_range_sep('-')
+    ///           ).parse_after("pages=1-5,10", "pages=")?;
+    /// assert_eq!(rng.collect::<Vec<usize>>(), vec![1, 2, 3, 4, 5, 10]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_after(self, numstr: &'a str, prefix: &str) -> Result<Self> {
+        match numstr.strip_prefix(prefix) {
+            Some(numstr) => self.parse_str(numstr),
+            None => Err::<Self, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{} Doesn't start with {}", numstr, prefix)),
+        }
+    }
+
+    /// Parse the human readable string from bytes, the bytes must be
+    /// valid UTF-8.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_bytes(b"1,3:5")?;
+    /// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 4, 5]);
+    /// assert!(NumberRange::<i64>::default().parse_bytes(b"1,\xff").is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_bytes(self, bytes: &'a [u8]) -> Result<Self> {
+        let numstr = std::str::from_utf8(bytes).with_context(|| "Not a valid UTF-8 string")?;
+        self.parse_str(numstr)
+    }
+
+    /// Parse the human readable string from [`std::ffi::OsStr`] (for
+    /// example command line arguments), it must be valid UTF-8.
+    pub fn parse_os_str(self, numstr: &'a std::ffi::OsStr) -> Result<Self> {
+        match numstr.to_str() {
+            Some(numstr) => self.parse_str(numstr),
+            None => Err::<Self, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| format!("{:?} Not a valid UTF-8 string", numstr)),
+        }
+    }
+
+    /// Read the whole human readable string from the `reader` (for
+    /// example a file) and parse it into the [`OwnedNumberRange<T>`].
+    /// Errors while reading have the [`std::io::Error`] as the root
+    /// cause, so they can be told apart from the parsing errors.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::io::Cursor;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_reader(Cursor::new(b"1,3:5\n"))?;
+    /// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 4, 5]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_reader<R: std::io::Read>(self, mut reader: R) -> Result<OwnedNumberRange<T>> {
+        let mut numstr = String::new();
+        reader
+            .read_to_string(&mut numstr)
+            .with_context(|| "Couldn't read the numbers")?;
+        self.parse_owned(&numstr)
+    }
+
+    pub fn from_vec<V>(self, nums: V, increment: Option<T>) -> Self
+    where
+        T: std::cmp::Ord,
+        V: IntoIterator<Item = T>,
+    {
+        let mut nums: Vec<T> = nums.into_iter().collect();
+        nums.sort();
+        self.from_vec_nosort(&nums, increment)
+    }
+
+    pub fn from_vec_nosort(mut self, nums: &[T], increment: Option<T>) -> Self
+    where
+        T: std::cmp::Ord,
+    {
+        self.original_repr = None;
+        self.padding_width = None;
+        self.parse_seps = None;
+        let inc = increment.unwrap_or(num::one());
+        self.numbers.clear();
+        if !nums.is_empty() {
+            let mut first = &nums[0];
+            let mut prev = &nums[0];
+            let mut rng = false;
+            for current in &nums[1..] {
+                if current == prev {
+                    continue;
+                }
+                if *current == (*prev + inc) {
+                    if !rng {
+                        rng = true;
+                        first = prev;
+                    }
+                } else {
+                    if rng {
+                        self.numbers.push_back(Number::Range(*first, inc, *prev));
+                    } else {
+                        self.numbers.push_back(Number::Single(*prev));
+                    }
+                    rng = false;
+                }
+                prev = current;
+            }
+            if rng {
+                self.numbers.push_back(Number::Range(*first, inc, *prev));
+            } else {
+                self.numbers.push_back(Number::Single(*prev));
+            }
+        }
+        self
+    }
+
+    /// Structured representation of the numbers as `(start, step,
+    /// end)`, where the step is `None` when it is one. Single numbers
+    /// have the same start and end, and the repeated values are given
+    /// as that many single numbers (e.g. `"3x5"` is three `(5, None,
+    /// 5)`). The ranges without values are left out, so a positive
+    /// step with the start greater than the end is always the
+    /// descending range (e.g. for the unsigned types).
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1,3:10,20:-2:14")?;
+    /// let config = rng.to_config();
+    /// assert_eq!(config, vec![(1, None, 1), (3, None, 10), (20, Some(-2), 14)]);
+    /// let rng = NumberRange::<i64>::default().from_config(config)?;
+    /// assert_eq!(format!("{}", rng), "1,3:10,20:-2:14");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_config(&self) -> Vec<(T, Option<T>, T)> {
+        let mut config = Vec::with_capacity(self.numbers.len());
+        for num in self.numbers.iter().filter(|n| n.is_valid()) {
+            match *num {
+                Number::Single(v) => config.push((v, None, v)),
+                Number::Range(start, step, end) => {
+                    config.push((start, Some(step).filter(|s| !s.is_one()), end))
+                }
+                Number::RevRange(start, step, end) => config.push((start, Some(step), end)),
+                Number::Repeat(v, count) => config.extend(std::iter::repeat_n((v, None, v), count)),
+            }
+        }
+        config
+    }
+
+    /// Build from the structured representation given by
+    /// [`NumberRange::to_config()`]. A positive step with start
+    /// greater than end makes a [`Number::RevRange`]. The entries are
+    /// checked like the parsed ranges (e.g. the step can't be zero,
+    /// and `autofix_direction` swaps the bounds), and as
+    /// [`NumberRange::to_config()`] doesn't give the ranges without
+    /// values, those are errors.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<usize>::default().from_config([(10, Some(3), 1), (4, None, 5)])?;
+    /// assert_eq!(rng.collect::<Vec<usize>>(), vec![10, 7, 4, 1, 4, 5]);
+    /// assert!(NumberRange::<usize>::default().from_config([(1, Some(0), 5)]).is_err());
+    /// assert!(NumberRange::<i64>::default().from_config([(1, Some(-1), 5)]).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_config<V>(mut self, config: V) -> Result<Self>
+    where
+        V: IntoIterator<Item = (T, Option<T>, T)>,
+    {
+        self.original_repr = None;
+        self.padding_width = None;
+        self.parse_seps = None;
+        let options = &self.options;
+        let numbers = config
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, (start, step, end))| {
+                let entry = format!("Config entry {}", i);
+                let num = (|| {
+                    let start = NumberRangeOptions::check_finite(start, &entry)?;
+                    let end = NumberRangeOptions::check_finite(end, &entry)?;
+                    let num = match step {
+                        None if start == end => return Ok(Some(Number::Single(start))),
+                        Some(step) if start > end && step > T::zero() => {
+                            Number::RevRange(start, step, end)
+                        }
+                        _ => Number::Range(start, step.unwrap_or(T::one()), end),
+                    };
+                    let num = NumberRangeOptions::check_step(num, &entry)?;
+                    match options.equal_bounds(options.autofix_direction(num)) {
+                        Some(num) if num.is_invalid() => {
+                            Err::<_, anyhow::Error>(NumberRangeError {}.into())
+                                .with_context(|| format!("{} Range has no values", entry))
+                        }
+                        num => options.check_count(num, &entry),
+                    }
+                })();
+                num.transpose()
+            })
+            .collect::<Result<VecDeque<Number<T>>>>()?;
+        self.numbers = numbers;
+        Ok(self)
+    }
+
+    pub fn parse(mut self) -> Result<Self> {
+        if let Some(numstr) = self.original_repr.as_deref() {
+            self.options.check_separators()?;
+            self.padding_width = None;
+            self.parse_seps = Some((self.options.list_sep, self.options.range_sep));
+            if self.options.sanitize_number(numstr).is_empty() {
+                self.numbers.clear();
+                return Ok(self);
+            }
+            let segments = self.options.split_segments(numstr)?;
+            let numbers: VecDeque<Number<T>> = segments
+                .iter()
+                .filter_map(|seq_str| {
+                    self.options
+                        .parse_token(seq_str, self.relative_base)
+                        .transpose()
+                })
+                .collect::<Result<VecDeque<Number<T>>>>()?;
+            self.numbers = numbers;
+            self.padding_width = segments
+                .iter()
+                .filter_map(|seq_str| self.options.padding_width(seq_str))
+                .max();
+            Ok(self)
+        } else {
+            Err::<NumberRange<'_, _>, anyhow::Error>(NumberRangeError {}.into())
+                .with_context(|| "Nothing to Parse".to_string())
+        }
+    }
+}
+
+impl<'a, T: num::PrimInt> NumberRange<'a, T> {
+    /// Iterate through the values in ascending order without
+    /// collecting them, the segments are merged as they're iterated.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("9,1:3,5,8:-2:4")?;
+    /// assert_eq!(rng.iter_sorted().collect::<Vec<i64>>(), vec![1, 2, 3, 4, 5, 6, 8, 9]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = T> {
+        let segments = self.numbers.iter().filter_map(Number::ascending).collect();
+        Self::merge_segments(segments, |v| v)
+    }
+
+    /// Merges the segments that are each in the order of the `key`,
+    /// so the values come out in that order.
+    fn merge_segments<K: Ord>(
+        mut segments: Vec<Number<T>>,
+        key: impl Fn(T) -> K,
+    ) -> impl Iterator<Item = T> {
+        let mut heap: BinaryHeap<Reverse<(K, usize)>> = segments
+            .iter()
+            .enumerate()
+            .filter_map(|(i, n)| n.split_first().map(|(v, _)| Reverse((key(v), i))))
+            .collect();
+        std::iter::from_fn(move || {
+            let Reverse((_, i)) = heap.pop()?;
+            let (value, rest) = segments[i].split_first()?;
+            if let Some(rest) = rest {
+                if let Some((next, _)) = rest.split_first() {
+                    heap.push(Reverse((key(next), i)));
+                }
+                segments[i] = rest;
+            }
+            Some(value)
+        })
+    }
+
+    /// Total number of values, computed without iterating.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10,14:2:20")?;
+    /// assert_eq!(rng.total_count(), 14);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn total_count(&self) -> usize {
+        self.numbers
+            .iter()
+            .map(Number::count)
+            .fold(0, usize::saturating_add)
+    }
+
+    /// Number of values that are multiples of `n`, the ranges with
+    /// the step of one are counted without iterating.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:100,-7:-1,105")?;
+    /// assert_eq!(rng.count_multiples_of(5), 22);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn count_multiples_of(&self, n: T) -> usize {
+        let size = Number::magnitude(n);
+        // the remainder of `MIN % -1` overflows
+        let multiple = |v: T| match size {
+            0 => v.is_zero(),
+            1 => true,
+            _ => (v % n).is_zero(),
+        };
+        // multiples from `start` to `end`, counted from the first one
+        let multiples = |start: T, end: T| {
+            let rem = if size == 1 { T::zero() } else { start % n };
+            let to_first = match rem {
+                r if r.is_zero() => 0,
+                r if r < T::zero() => Number::magnitude(r),
+                r => size - Number::magnitude(r),
+            };
+            let span = Number::distance(start, end);
+            if to_first > span {
+                0
+            } else {
+                usize::try_from((span - to_first) / size + 1).unwrap_or(usize::MAX)
+            }
+        };
+        self.numbers
+            .iter()
+            .filter_map(Number::ascending)
+            .map(|num| match num {
+                Number::Range(start, step, end) if step.is_one() && size != 0 => {
+                    multiples(start, end)
+                }
+                Number::Repeat(v, count) if multiple(v) => count,
+                num => num.iter().filter(|v| multiple(*v)).count(),
+            })
+            .sum()
+    }
+
+    /// Iterate through the values with the number of values still
+    /// remaining after each one, see also
+    /// [`NumberRange::iter_with_last()`].
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3")?;
+    /// assert_eq!(rng.iter_with_remaining().collect::<Vec<(i64, usize)>>(),
+    ///            vec![(1, 2), (2, 1), (3, 0)]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_with_remaining(self) -> impl Iterator<Item = (T, usize)> + 'a
+    where
+        T: 'a,
+    {
+        let total = self.total_count();
+        self.enumerate().map(move |(i, v)| (v, total - i - 1))
+    }
+
+    /// Value at the index `n` of the values, computed without
+    /// iterating through the ranges.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10,14:2:20")?;
+    /// assert_eq!(rng.nth_value(11), Some(16));
+    /// assert_eq!(rng.nth_value(14), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn nth_value(&self, mut n: usize) -> Option<T> {
+        for num in &self.numbers {
+            let count = num.count();
+            if n < count {
+                return num.nth(n);
+            }
+            n -= count;
+        }
+        None
+    }
+
+    /// Index of the first `value` in the values, computed without
+    /// iterating through the ranges. Opposite of
+    /// [`NumberRange::nth_value()`].
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10,14:2:20")?;
+    /// assert_eq!(rng.index_of(16), Some(11));
+    /// assert_eq!(rng.index_of(15), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn index_of(&self, value: T) -> Option<usize> {
+        let mut offset = 0;
+        for num in &self.numbers {
+            if let Some(i) = num.index_of(value) {
+                return Some(offset + i);
+            }
+            offset += num.count();
+        }
+        None
+    }
+
+    /// Checks if the values are strictly increasing, without
+    /// iterating through the ranges. The ranges without values are
+    /// skipped.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// assert!(NumberRange::<i64>::default().parse_str("1:5,8")?.is_monotonic_increasing());
+    /// assert!(!NumberRange::<i64>::default().parse_str("1:5,3")?.is_monotonic_increasing());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_monotonic_increasing(&self) -> bool {
+        let mut previous: Option<T> = None;
+        for num in &self.numbers {
+            let (first, last) = match (num.nth(0), num.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => continue,
+            };
+            let single = num.count() == 1;
+            let ascending = match num {
+                Number::Range(_, step, _) => single || *step > T::zero(),
+                _ => single,
+            };
+            if !ascending || previous.is_some_and(|prev| first <= prev) {
+                return false;
+            }
+            previous = Some(last);
+        }
+        true
+    }
+
+    /// Value at the fraction `t` (from `0.0` for the first to `1.0`
+    /// for the last) of the values, rounded to the nearest one.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("0:10")?;
+    /// assert_eq!(rng.value_at_fraction(0.5), Some(5));
+    /// assert_eq!(rng.value_at_fraction(1.0), Some(10));
+    /// assert_eq!(rng.value_at_fraction(1.5), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn value_at_fraction(&self, t: f64) -> Option<T> {
+        let total = self.total_count();
+        if total == 0 || !(0.0..=1.0).contains(&t) {
+            return None;
+        }
+        self.nth_value((t * (total - 1) as f64).round() as usize)
+    }
+
+    /// Step between the values if all of them make a single
+    /// arithmetic sequence, even if they're written separately.
+    /// Descending sequences of unsigned numbers don't have a step.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("2,4,6:2:12")?;
+    /// assert_eq!(rng.common_step(), Some(2));
+    /// let rng = NumberRange::<i64>::default().parse_str("2,4,7")?;
+    /// assert_eq!(rng.common_step(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn common_step(&self) -> Option<T> {
+        let mut values = self.iter();
+        let first = values.next()?;
+        let mut prev = values.next()?;
+        let step = prev.checked_sub(&first).filter(|s| !s.is_zero())?;
+        for v in values {
+            if v.checked_sub(&prev) != Some(step) {
+                return None;
+            }
+            prev = v;
+        }
+        Some(step)
+    }
+
+    /// First and last values of each number/range, single numbers
+    /// and ranges with one value give it once. Invalid ranges are
+    /// skipped.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:5,8,10:2:20")?;
+    /// assert_eq!(rng.boundaries(), vec![1, 5, 8, 10, 20]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn boundaries(&self) -> Vec<T> {
+        let mut bounds = Vec::new();
+        for num in &self.numbers {
+            if let (Some((first, _)), Some(last)) = (num.split_first(), num.last()) {
+                bounds.push(first);
+                if last != first {
+                    bounds.push(last);
+                }
+            }
+        }
+        bounds
+    }
+
+    /// Split the values into `parts` contiguous parts of nearly
+    /// equal sizes, the first ones get the extra values.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10")?;
+    /// let parts: Vec<String> = rng.split_into(3).iter().map(|p| p.to_string()).collect();
+    /// assert_eq!(parts, vec!["1:4", "5:7", "8:10"]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_into(&self, parts: usize) -> Vec<NumberRange<'a, T>> {
+        if parts == 0 {
+            return Vec::new();
+        }
+        let total = self.total_count();
+        let sizes = (0..parts).map(|i| total / parts + usize::from(i < total % parts));
+        let mut segments = self.numbers.iter().filter(|n| n.is_valid());
+        let mut current = segments.next();
+        let mut used = 0;
+        sizes
+            .map(|size| {
+                let mut numbers = VecDeque::new();
+                let mut needed = size;
+                while let (Some(num), true) = (current, needed > 0) {
+                    let left = num.count() - used;
+                    numbers.extend(num.slice(used, used + needed));
+                    if needed < left {
+                        used += needed;
+                        needed = 0;
+                    } else {
+                        needed -= left;
+                        current = segments.next();
+                        used = 0;
+                    }
+                }
+                NumberRange {
+                    numbers,
+                    original_repr: None,
+                    padding_width: None,
+                    parse_seps: None,
+                    relative_base: None,
+                    options: self.options.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Value of the number/range nearest to `value`, when the numbers
+    /// are a single number/range, like snapping a slider to the
+    /// steps. The halfway values go to the larger one.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("0:5:20")?;
+    /// assert_eq!(rng.snap(7), Some(5));
+    /// assert_eq!(rng.snap(8), Some(10));
+    /// assert_eq!(rng.snap(100), Some(20));
+    /// let rng = NumberRange::<i64>::default().parse_str("0:5:20,30")?;
+    /// assert_eq!(rng.snap(7), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn snap(&self, value: T) -> Option<T> {
+        if self.numbers.len() != 1 {
+            return None;
+        }
+        let (first, step, last) = match self.numbers[0].ascending()? {
+            Number::Single(v) | Number::Repeat(v, _) => return Some(v),
+            Number::Range(start, step, end) => (start, Number::magnitude(step), end),
+            Number::RevRange(..) => unreachable!("ascending never gives RevRange"),
+        };
+        let offset = Number::distance(first, value.clamp(first, last));
+        // halfway values round up, the last value is on the steps
+        let steps = offset / step + u128::from(offset % step >= step - step / 2);
+        Number::forward(first, steps * step)
+    }
+
+    /// Python slice notation (`start:stop:step`) with the exclusive
+    /// stop, when the numbers are a single number/range. Descending
+    /// ranges that go till zero don't have the stop, as negative
+    /// values mean something else in Python.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:2:9")?;
+    /// assert_eq!(rng.to_python_slice(), Some("1:11:2".to_string()));
+    /// let rng = NumberRange::<i64>::default().parse_str("1:2:9,20")?;
+    /// assert_eq!(rng.to_python_slice(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_python_slice(&self) -> Option<String>
+    where
+        T: std::fmt::Display,
+    {
+        if self.numbers.len() != 1 {
+            return None;
+        }
+        let num = self.numbers[0];
+        let last = num.last()?;
+        let (start, stop, step) = match num {
+            Number::Single(v) => (v, v.checked_add(&T::one()), None),
+            Number::Range(start, step, _) if step > T::zero() => {
+                (start, last.checked_add(&step), Some(step))
+            }
+            Number::Range(start, step, _) => {
+                let stop = last.checked_add(&step).filter(|s| *s >= T::zero());
+                return Some(match stop {
+                    Some(stop) => format!("{}:{}:{}", start, stop, step),
+                    None => format!("{}::{}", start, step),
+                });
+            }
+            Number::RevRange(start, step, _) => {
+                return Some(match last.checked_sub(&step) {
+                    Some(stop) => format!("{}:{}:-{}", start, stop, step),
+                    None => format!("{}::-{}", start, step),
+                });
+            }
+            Number::Repeat(..) => return None,
+        };
+        let stop = stop?;
+        Some(match step.filter(|s| !s.is_one()) {
+            Some(step) => format!("{}:{}:{}", start, stop, step),
+            None => format!("{}:{}", start, stop),
+        })
+    }
+
+    /// Run length encoding of the sorted values, as the start of each
+    /// run of consecutive values and its length. Repeated values are
+    /// only counted once.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:5,8,10:12")?;
+    /// assert_eq!(rng.to_rle(), vec![(1, 5), (8, 1), (10, 3)]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_rle(&self) -> Vec<(T, usize)> {
+        self.runs()
+            .into_iter()
+            .map(|(start, end)| (start, Number::Range(start, T::one(), end).count()))
+            .collect()
+    }
+
+    /// Sorted and merged contiguous runs of the values as inclusive
+    /// `(start, end)`, like for the `BETWEEN start AND end` clauses
+    /// in SQL. Ranges with steps other than one are expanded.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:3,5,7:9")?;
+    /// assert_eq!(rng.to_between_clauses(), vec![(1, 3), (5, 5), (7, 9)]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_between_clauses(&self) -> Vec<(T, T)> {
+        self.runs()
+    }
+
+    /// The `k` smallest values in ascending order, each value only
+    /// once. The segments are merged lazily, so only the values
+    /// needed are generated. With `itertools::Itertools` in scope the
+    /// method call picks its `k_smallest` for the iterators, so call
+    /// it as `NumberRange::k_smallest(&rng, k)`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("9,1:3,20:22")?;
+    /// assert_eq!(NumberRange::k_smallest(&rng, 4), vec![1, 2, 3, 9]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn k_smallest(&self, k: usize) -> Vec<T> {
+        self.iter_sorted().dedup().take(k).collect()
+    }
+
+    /// The `k` largest values in descending order, each value only
+    /// once. The segments are merged lazily from their ends, so only
+    /// the values needed are generated. Like
+    /// [`NumberRange::k_smallest()`], call it as
+    /// `NumberRange::k_largest(&rng, k)`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("9,1:3,20:22")?;
+    /// assert_eq!(NumberRange::k_largest(&rng, 4), vec![22, 21, 20, 9]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn k_largest(&self, k: usize) -> Vec<T> {
+        let segments = self
+            .numbers
+            .iter()
+            .filter_map(|n| match n.ascending()? {
+                Number::Range(first, step, last) => Some(Number::RevRange(last, step, first)),
+                n => Some(n),
+            })
+            .collect();
+        Self::merge_segments(segments, Reverse)
+            .dedup()
+            .take(k)
+            .collect()
+    }
+
+    /// Sorted and merged contiguous runs `(start, end)` of the
+    /// values. Ranges with steps other than one are expanded.
+    fn runs(&self) -> Vec<(T, T)> {
+        let mut runs: Vec<(T, T)> = Vec::new();
+        for num in self.numbers.iter().filter_map(Number::ascending) {
+            match num {
+                Number::Range(start, step, end) if step.is_one() => runs.push((start, end)),
+                Number::Repeat(v, _) => runs.push((v, v)),
+                _ => runs.extend(num.iter().map(|v| (v, v))),
+            }
+        }
+        Self::merge_runs(runs)
+    }
+
+    /// Merged contiguous runs, and the ranges with the other steps, as
+    /// the [`Progression`]s of the values. Both are sorted, and the
+    /// stepped ones are kept as they are instead of being expanded.
+    fn progressions(&self) -> (Vec<Progression>, Vec<Progression>) {
+        let mut runs = Vec::new();
+        let mut stepped = Vec::new();
+        for p in self.numbers.iter().filter_map(Number::progression) {
+            if p.step == 1 {
+                runs.push((p.first, p.last));
+            } else {
+                stepped.push(p);
+            }
+        }
+        stepped.sort();
+        stepped.dedup();
+        let runs = NumberRange::<u128>::merge_runs(runs)
+            .into_iter()
+            .filter_map(|(first, last)| Progression::new(first, 1, last))
+            .collect();
+        (runs, stepped)
+    }
+
+    /// All the [`Progression`]s of the values, sorted.
+    fn segments(&self) -> Vec<Progression> {
+        let (mut segments, stepped) = self.progressions();
+        segments.extend(stepped);
+        segments.sort();
+        segments
+    }
+
+    /// Sort the runs and merge the overlapping or adjacent ones.
+    fn merge_runs(mut runs: Vec<(T, T)>) -> Vec<(T, T)> {
+        runs.sort();
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(runs.len());
+        for (start, end) in runs {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(T::one()) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+
+    /// Numbers made from the [`Progression`]s of the keys, with the
+    /// same options. The steps too large for the type only have a few
+    /// values, those are kept as the single numbers.
+    fn with_progressions(&self, progressions: Vec<Progression>) -> NumberRange<'a, T> {
+        let value = |key: u128| Number::forward(T::min_value(), key);
+        let mut numbers = VecDeque::with_capacity(progressions.len());
+        for p in progressions {
+            let (first, last) = match (value(p.first), value(p.last)) {
+                (Some(first), Some(last)) => (first, last),
+                _ => continue,
+            };
+            match T::from(p.step) {
+                _ if first == last => numbers.push_back(Number::Single(first)),
+                Some(step) => numbers.push_back(Number::Range(first, step, last)),
+                None => numbers.extend(
+                    std::iter::successors(Some(p.first), |k| {
+                        k.checked_add(p.step).filter(|k| *k <= p.last)
+                    })
+                    .filter_map(value)
+                    .map(Number::Single),
+                ),
+            }
+        }
+        NumberRange {
+            numbers,
+            original_repr: None,
+            padding_width: None,
+            parse_seps: None,
+            relative_base: None,
+            options: self.options.clone(),
+        }
+    }
+
+    /// Values that are in exactly one of the numbers, as sorted
+    /// ranges. The ranges are not expanded, the stepped ones give the
+    /// stepped ranges.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng1 = NumberRange::<i64>::default().parse_str("1:10")?;
+    /// let rng2 = NumberRange::<i64>::default().parse_str("5:15")?;
+    /// assert_eq!(format!("{}", rng1.symmetric_difference(&rng2)), "1:4,11:15");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn symmetric_difference(&self, other: &NumberRange<T>) -> NumberRange<'a, T> {
+        self.with_progressions(symmetric_difference(&self.segments(), &other.segments()))
+    }
+
+    /// Values of the type `T` that are not in the numbers, as sorted
+    /// and merged ranges. The ranges are not expanded (the stepped
+    /// ones leave the stepped gaps), iterating over them can still
+    /// take long for the large types.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<u8>::default().parse_str("0:253")?;
+    /// assert_eq!(rng.complement_in_type().collect::<Vec<u8>>(), vec![254, 255]);
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10")?;
+    /// assert_eq!(
+    ///     format!("{}", rng.complement_in_type()),
+    ///     format!("{}:0,11:{}", i64::MIN, i64::MAX)
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement_in_type(&self) -> NumberRange<'a, T> {
+        let all = Progression::new(0, 1, Number::key(T::max_value()));
+        self.with_progressions(symmetric_difference(&self.segments(), all.as_slice()))
+    }
+
+    /// Checks if both have the same values, regardless of their order
+    /// or how many times they come. The ranges are compared without
+    /// expanding them, even the ones with steps.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:5")?;
+    /// assert!(rng.same_set_as(&NumberRange::default().parse_str("5,4,3,2,1,1")?));
+    /// assert!(!rng.same_set_as(&NumberRange::default().parse_str("1:6")?));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn same_set_as(&self, other: &NumberRange<T>) -> bool {
+        symmetric_difference(&self.segments(), &other.segments()).is_empty()
+    }
+
+    /// Checks if all the values are also in the `other` numbers. The
+    /// ranges are compared without expanding them, none of the values
+    /// are left out of the ones shared with the `other` numbers.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let allowed = NumberRange::<i64>::default().parse_str("1:10,20:30")?;
+    /// let requested = NumberRange::<i64>::default().parse_str("2:5,22")?;
+    /// assert!(requested.is_subset_of(&allowed));
+    /// assert!(!allowed.is_subset_of(&requested));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_subset_of(&self, other: &NumberRange<T>) -> bool {
+        let own = self.segments();
+        if own.is_empty() {
+            return true;
+        }
+        let common: Vec<Progression> = intersections(&own, &other.segments()).collect();
+        symmetric_difference(&own, &common).is_empty()
+    }
+
+    /// Checks if any value is in both numbers. The ranges are compared
+    /// without expanding them, the stepped ones share a value when
+    /// their steps line up within the overlap.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10,20:30")?;
+    /// assert!(rng.overlaps(&NumberRange::default().parse_str("8:12")?));
+    /// assert!(!rng.overlaps(&NumberRange::default().parse_str("11:19")?));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlaps(&self, other: &NumberRange<T>) -> bool {
+        intersections(&self.segments(), &other.segments())
+            .next()
+            .is_some()
+    }
+
+    /// Number of distinct values in both numbers, the overlaps of the
+    /// ranges (stepped or not) are counted without expanding them. It
+    /// saturates at `usize::MAX`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1:10,20:30")?;
+    /// assert_eq!(rng.intersection_count(&NumberRange::default().parse_str("8:22")?), 6);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersection_count(&self, other: &NumberRange<T>) -> usize {
+        let common: Vec<Progression> = intersections(&self.segments(), &other.segments()).collect();
+        if common.is_empty() {
+            return 0;
+        }
+        match union_count(&common) {
+            // all the values of u128
+            0 => usize::MAX,
+            count => usize::try_from(count).unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Closure that checks if a value is in the numbers, the runs
+    /// are merged once so each check is a binary search. The ranges
+    /// with other steps are checked one by one, without expanding.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRangeOptions;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let selection = NumberRangeOptions::<u32>::new().with_range_sep('-').parse("1,4,6-8")?;
+    /// let data: Vec<u32> = (0..10).filter(selection.into_predicate()).collect();
+    /// assert_eq!(data, vec![1, 4, 6, 7, 8]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn into_predicate(&self) -> impl Fn(&T) -> bool {
+        let (runs, stepped) = self.progressions();
+        move |value| {
+            let key = Number::key(*value);
+            let i = runs.partition_point(|p| p.first <= key);
+            (i > 0 && runs[i - 1].last >= key) || stepped.iter().any(|p| p.contains(key))
+        }
+    }
+
+    /// Trim the numbers/ranges to only have the values within the
+    /// `bounds`, the ones entirely outside are removed.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut rng = NumberRange::<i64>::default().parse_str("1:20,30,18:-3:1")?;
+    /// rng.clamp_to_range(5..=15);
+    /// assert_eq!(format!("{}", rng), "5:15,15:-3:6");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn clamp_to_range(&mut self, bounds: std::ops::RangeInclusive<T>) {
+        let (low, high) = bounds.into_inner();
+        self.numbers = self
+            .numbers
+            .iter()
+            .filter_map(|n| n.clamp(low, high))
+            .collect();
+    }
+
+    /// Flip the descending ranges into ascending ones with the same
+    /// values, and sort the numbers/ranges by their first value.
+    /// Invalid ranges are removed as they have no values.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut rng = NumberRange::<i64>::default().parse_str("20,10:-1:1")?;
+    /// rng.to_ascending();
+    /// assert_eq!(format!("{}", rng), "1:10,20");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_ascending(&mut self) {
+        self.numbers = self.numbers.iter().filter_map(Number::ascending).collect();
+        self.numbers
+            .make_contiguous()
+            .sort_by_key(|n| n.split_first().map(|(v, _)| v));
+    }
+
+    /// Binary string (`0b...`) of the given width, where the bit `i`
+    /// (from the right) is set if `i` is in the numbers. Numbers
+    /// outside the width are ignored.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<u8>::default().parse_str("0,1,3")?;
+    /// assert_eq!(rng.to_mask_string(8), "0b00001011");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_mask_string(&self, width: usize) -> String {
+        if width == 0 {
+            return "0b".to_string();
+        }
+        let mut bits = vec!['0'; width];
+        let zero = Number::key(T::zero());
+        let high = T::from(width - 1).map_or(u128::MAX, Number::key);
+        // only the values inside the width are generated
+        let clamped = self
+            .numbers
+            .iter()
+            .filter_map(|n| Progression::clamp(&n.progression()?, zero, high));
+        for p in clamped {
+            for key in (p.first..=p.last).step_by(p.step as usize) {
+                bits[width - 1 - (key - zero) as usize] = '1';
+            }
+        }
+        format!("0b{}", bits.into_iter().collect::<String>())
+    }
+
+    /// Mask of the values from `lo` to `hi` (inclusive), where the
+    /// index `i` is `true` if `lo + i` is in the numbers. It's empty
+    /// when `hi` is less than `lo`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<i64>::default().parse_str("1,3:4")?;
+    /// assert_eq!(rng.to_bool_mask(0, 5), vec![false, true, false, true, true, false]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_bool_mask(&self, lo: T, hi: T) -> Vec<bool> {
+        if hi < lo {
+            return vec![];
+        }
+        let (low, high) = (Number::key(lo), Number::key(hi));
+        let len = match usize::try_from(high - low)
+            .ok()
+            .and_then(|d| d.checked_add(1))
+        {
+            Some(len) => len,
+            None => return vec![],
+        };
+        let mut mask = vec![false; len];
+        // only the values between lo and hi are generated
+        let clamped = self
+            .numbers
+            .iter()
+            .filter_map(|n| Progression::clamp(&n.progression()?, low, high));
+        for p in clamped {
+            for key in (p.first..=p.last).step_by(p.step as usize) {
+                mask[(key - low) as usize] = true;
+            }
+        }
+        mask
+    }
+}
+
+impl<'a, T: num::Float> NumberRange<'a, T> {
+    /// Collect the values rounded to the nearest integer, the halfway
+    /// values are rounded away from zero. Values that don't fit in
+    /// `I` are skipped.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use number_range::NumberRange;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let rng = NumberRange::<f64>::default().parse_str("1:0.5:3")?;
+    /// assert_eq!(rng.collect_rounded::<i64>(), vec![1, 2, 2, 3, 3]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Use [`Vec::dedup()`] on the result if you don't want the
+    /// repeated values from steps smaller than one.
+    pub fn collect_rounded<I: num::NumCast>(self) -> Vec<I> {
+        self.filter_map(|v| I::from(v.round())).collect()
+    }
+}
+
+/// Parse the string with the default options and collect the numbers
+/// into a `Vec<T>`, for when you don't need the [`NumberRange<T>`].
+///
+/// ```rust
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let v = number_range::parse::<i64>("1,3:6")?;
+/// assert_eq!(v, vec![1, 3, 4, 5, 6]);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn parse<T: std::str::FromStr + num::Num + num::NumCast + Copy + std::cmp::PartialOrd>(
+    numstr: &str,
+) -> Result<Vec<T>>
+where
+    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(NumberRange::default().parse_str(numstr)?.collect())
+}
+
+/// Macro rule for generating number range. The [`NumberRange<T>`] is
+/// made with default options, then parsed.
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use number_range::numrng;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let mut rng = numrng!(1,3:2:6,-4:-2);
+/// assert_eq!(format!("{}", rng), "1,3:2:6,-4:-2");
+/// assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 5, -4, -3, -2]);
+/// assert_eq!(numrng!().collect::<Vec<i64>>(), vec![]);
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! numrng {
+    ($($l:tt)*) => {
+	// stringify seems to introduce extra spaces between all characters
+        $crate::NumberRangeOptions::new().with_whitespace(true).parse(stringify!($($l)*)).unwrap()
+    };
+}
+
+/// Macro rule for generating number range into vec. The
+/// [`NumberRange<T>`] is made with default options, then parsed and
+/// collected into a Vec<T>.
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use number_range::numvec;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let mut rng: Vec<i64> = numvec!(1,3:2:6,-4:-2);
+/// assert_eq!(rng, vec![1, 3, 5, -4, -3, -2]);
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! numvec {
+    ($($l:tt)*) => {
+        $crate::numrng!($($l)*)
+            .collect()
+    };
+}
+
+#[cfg(test)]
+use rstest::rstest;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    fn manual_build() {
+        let mut rng = NumberRange::<i64>::default();
+        rng.numbers.push_back(Number::Single(1));
+        rng.numbers.push_back(Number::Range(3, 2, 6));
+        rng.numbers.push_back(Number::Range(-4, 1, -2));
+        assert_eq!(format!("{}", rng), "1,3:2:6,-4:-2");
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 5, -4, -3, -2]);
+    }
+
+    #[rstest]
+    fn manual_build_sanitize() {
+        let mut rng = NumberRange::<i64>::default();
+        rng.numbers.push_back(Number::Range(3, -2, 6));
+        rng.numbers.push_back(Number::Single(1));
+        rng.numbers.push_back(Number::Range(3, 2, 6));
+        rng.numbers.push_back(Number::Range(6, 0, 6));
+        rng.numbers.push_back(Number::Range(-2, 1, -4));
+        rng.numbers.push_back(Number::Range(-2, -1, -4));
+        assert_eq!(rng.sanitize_segments(), 3);
+        assert_eq!(
+            rng.numbers,
+            vec![
+                Number::Single(1),
+                Number::Range(3, 2, 6),
+                Number::Range(-2, -1, -4)
+            ]
+        );
+        assert_eq!(rng.sanitize_segments(), 0);
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 5, -2, -3, -4]);
+    }
+
+    #[rstest]
+    fn manual_build_results() {
+        let mut rng = NumberRange::<i64>::default();
+        rng.numbers.push_back(Number::Single(1));
+        rng.numbers.push_back(Number::Range(3, -2, 6));
+        rng.numbers.push_back(Number::Range(3, 2, 6));
+        let results: Vec<Result<i64>> = rng.iter_results().collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &1);
+        let err = results[1].as_ref().unwrap_err();
+        assert!(err.to_string().contains("3:-2:6"));
+        assert!(err.downcast_ref::<NumberRangeError>().is_some());
+        assert_eq!(results[2].as_ref().unwrap(), &3);
+        assert_eq!(results[3].as_ref().unwrap(), &5);
+    }
+
+    #[rstest]
+    fn options_build() {
+        let rng: NumberRange<usize> = NumberRangeOptions::<usize>::default()
+            .with_list_sep('*')
+            .with_range_sep('/')
+            .parse("1*3/5*9/2/15")
+            .expect("Parsing should be succesful");
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![1, 3, 4, 5, 9, 11, 13, 15]);
+    }
+
+    #[rstest]
+    #[allow(clippy::needless_range_loop, clippy::useless_vec)]
+    fn manual_build_then_modify() {
+        let mut rng = NumberRange::<i64>::default();
+        rng.numbers.push_back(Number::Single(1));
+        rng.numbers.push_back(Number::Range(3, 2, 6));
+        rng.numbers.push_back(Number::Range(-4, 1, -2));
+        let values = vec![1, 3, 5, -4, -3, -2];
+        assert_eq!(format!("{}", rng), "1,3:2:6,-4:-2");
+        for i in 0..4 {
+            assert_eq!(rng.next().expect("Should have next"), values[i]);
+        }
+        assert_eq!(format!("{}", rng), "-3:-2");
+        rng.numbers.push_back(Number::Single(1));
+        assert_eq!(format!("{}", rng), "-3:-2,1");
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![-3, -2, 1]);
+    }
+
+    #[rstest]
+    #[allow(clippy::needless_range_loop, clippy::useless_vec)]
+    fn options_build_then_modify() {
+        let mut rng: NumberRange<usize> = NumberRangeOptions::<usize>::default()
+            .with_list_sep(':')
+            .with_range_sep('-')
+            .parse("1:3-5:9")
+            .expect("Parsing should be succesful");
+        let values = vec![1, 3, 4, 5, 9];
+        for i in 0..4 {
+            assert_eq!(rng.next().expect("Should have next"), values[i]);
+        }
+        assert_eq!(format!("{}", rng), "9");
+        rng.numbers.push_back(Number::Range(11, 2, 15));
+        assert_eq!(format!("{}", rng), "9:11-2-15");
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![9, 11, 13, 15]);
+    }
+
+    #[rstest]
+    #[case("200", vec![200])]
+    #[case("-200", vec![-200])]
+    #[case("1,4", vec![1, 4])]
+    #[case("1, -4", vec![1, -4])]
+    #[should_panic]
+    #[case("1.0, 2", vec![])]
+    #[case("1: 3", vec![1, 2, 3])]
+    #[case(" -1:10", vec![-1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10])]
+    #[case("3 , 5:10", vec![3, 5, 6, 7, 8, 9, 10])]
+    fn comma_default_int(#[case] numstr: &str, #[case] numvec: Vec<i64>) {
+        assert_eq!(
+            NumberRange::<i64>::default()
+                .parse_str(numstr)
+                .unwrap()
+                .collect::<Vec<i64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    fn limits_test() {
+        let nr1: Vec<usize> = NumberRangeOptions::<usize>::new()
+            .with_range_sep('-')
+            .with_default_start(0)
+            .parse("-2")
+            .unwrap()
+            .collect();
+        assert_eq!(nr1, vec![0, 1, 2]);
+        let nr1: Vec<usize> = NumberRangeOptions::<usize>::new()
+            .with_range_sep('-')
+            .with_default_end(5)
+            .parse("2-")
+            .unwrap()
+            .collect();
+        assert_eq!(nr1, vec![2, 3, 4, 5]);
+        let nr1: Vec<usize> = NumberRangeOptions::<usize>::new()
+            .with_range_sep('-')
+            .with_default_start(0)
+            .with_default_end(5)
+            .parse("-")
+            .unwrap()
+            .collect();
+        assert_eq!(nr1, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[rstest]
+    #[case("200", vec![200])]
+    #[case("1,4", vec![1, 4])]
+    #[should_panic]
+    #[case("1,-4", vec![])]
+    #[should_panic]
+    #[case(",4", vec![])]
+    #[should_panic]
+    #[case("1,,4", vec![])]
+    #[should_panic]
+    #[case("1,4,", vec![])]
+    #[should_panic]
+    #[case("1,4.0", vec![])]
+    #[case("1:3", vec![1, 2, 3])]
+    #[case("1:10", vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10])]
+    fn default_usize(#[case] numstr: &str, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRange::<usize>::default()
+                .parse_str(numstr)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("200", vec!["200"])]
+    #[case("1,4", vec!["1,4", "4"])]
+    #[case("1:4", vec!["1:4", "2:4", "3:4", "4:4"])]
+    #[case("10:-4:4", vec!["10:-4:4", "6:-4:4"])]
+    #[case("4:-1:1", vec!["4:-1:1", "3:-1:1", "2:-1:1", "1:-1:1"])]
+    #[case("1:4:10", vec!["1:4:10", "5:4:10", "9:4:10"])]
+    fn format_test_loop(#[case] numstr: &str, #[case] numvec: Vec<&str>) {
+        let mut rng: NumberRange<i64> = NumberRange::default().parse_str(numstr).unwrap();
+        for fmt_str in numvec {
+            assert_eq!(fmt_str, format!("{}", &rng));
+            rng.next();
+        }
+        assert!(rng.next().is_none());
+    }
+
+    #[rstest]
+    #[case("10:-3:0", vec!["10:-3:0", "7:-3:0", "4:-3:0", "1:-3:0"])]
+    #[case("4:-1:2", vec!["4:-1:2", "3:-1:2", "2:-1:2"])]
+    fn format_test_loop_usize(#[case] numstr: &str, #[case] numvec: Vec<&str>) {
+        let mut rng: NumberRange<usize> = NumberRange::default().parse_str(numstr).unwrap();
+        for fmt_str in numvec {
+            assert_eq!(fmt_str, format!("{}", &rng));
+            rng.next();
+        }
+        assert!(rng.next().is_none());
+    }
+
+    #[rstest]
+    #[case("10:-2:0", -2, vec![10, 8, 6, 4, 2, 0])]
+    #[case("10:-3:0", -3, vec![10, 7, 4, 1])]
+    #[case("3:-5:0", -5, vec![3])]
+    #[case("0:2:6", 2, vec![0, 2, 4, 6])]
+    fn signed_step_usize(#[case] numstr: &str, #[case] step: isize, #[case] numvec: Vec<usize>) {
+        let rng: NumberRange<usize> = NumberRange::default().parse_str(numstr).unwrap();
+        let (start, end) = match rng.numbers[0] {
+            Number::Range(start, _, end) | Number::RevRange(start, _, end) => (start, end),
+            _ => panic!("Should be a range"),
+        };
+        let step = SignedStep::from_signed(step).unwrap();
+        assert_eq!(rng.numbers[0], Number::with_signed_step(start, step, end));
+        assert_eq!(rng.collect::<Vec<usize>>(), numvec);
+    }
+
+    #[rstest]
+    fn signed_step_type_bounds() {
+        let step = SignedStep::<u8>::from_signed(isize::MIN);
+        assert_eq!(step, None);
+        let step = SignedStep::<u64>::from_signed(i64::MIN).unwrap();
+        assert_eq!(step, SignedStep::new(1 << 63, true));
+        let num = Number::with_signed_step(u64::MAX, step, 0);
+        assert_eq!(
+            num.iter().collect::<Vec<u64>>(),
+            vec![u64::MAX, (1 << 63) - 1]
+        );
+    }
+
+    #[rstest]
+    #[case("200", ',',vec![200])]
+    #[case("1,4", ',', vec![1, 4])]
+    #[case("1:4", ':', vec![1, 4])]
+    #[case("1:4:4", ':', vec![1, 4, 4])]
+    #[case("1/4", '/', vec![1, 4])]
+    #[should_panic]
+    #[case("1--4", '-', vec![])]
+    #[should_panic]
+    #[case("1,-4", ':', vec![])]
+    fn comma_test_sep_usize(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRangeOptions::<usize>::new()
+                .with_list_sep(sep)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("200", '-',vec![200])]
+    #[case("1-4", '-', vec![1,2,3,4])]
+    #[case("1:3:4", ':', vec![1, 4])]
+    #[case("4:-3:1", ':', vec![4, 1])]
+    #[case("10:-2:0", ':', vec![10, 8, 6, 4, 2, 0])]
+    #[case("10:-3:0", ':', vec![10, 7, 4, 1])]
+    #[case("0:-2:10", ':', vec![])]
+    #[should_panic]
+    #[case("1--4", '-', vec![])]
+    fn comma_test_range_usize(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRangeOptions::<usize>::new()
+                .with_range_sep(sep)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("200", '-',vec![200])]
+    #[case("1-4", '-', vec![1,2,3,4])]
+    #[case("1:3:4", ':', vec![1, 4])]
+    #[case("4:-3:1", ':', vec![4, 1])]
+    #[case("-4:1", ':', vec![-4, -3, -2, -1, 0, 1])]
+    #[case("1:-4", ':', vec![])]
+    fn comma_test_range_i64(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<i64>) {
+        assert_eq!(
+            NumberRangeOptions::<i64>::new()
+                .with_range_sep(sep)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<i64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("200", '-',vec![200.0])]
+    #[case("1-4", '-', vec![1.0,2.0,3.0,4.0])]
+    #[case("1:3:4", ':', vec![1.0, 4.0])]
+    #[case("1:.5:4", ':', vec![1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0])]
+    #[case("4:-3:1", ':', vec![4.0, 1.0])]
+    #[case("-4:1", ':', vec![-4.0, -3.0, -2.0, -1.0, 0.0, 1.0])]
+    #[case("1:-4", ':', vec![])]
+    #[case("-.5", ':', vec![-0.5])]
+    #[case("-.5:.5:1", ':', vec![-0.5, 0.0, 0.5, 1.0])]
+    #[case("1:-.5:-.5", ':', vec![1.0, 0.5, 0.0, -0.5])]
+    fn comma_test_range_f64(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<f64>) {
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .with_range_sep(sep)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("-,5", vec![-0.5])]
+    #[case("-0,5", vec![-0.5])]
+    #[case("-1,5:,5:0; 2", vec![-1.5, -1.0, -0.5, 0.0, 2.0])]
+    #[case("1:-,5:-,5", vec![1.0, 0.5, 0.0, -0.5])]
+    fn decimal_comma_negative_f64(#[case] numstr: &str, #[case] numvec: Vec<f64>) {
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .with_list_sep(';')
+                .with_decimal_sep(',')
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("0:10%:100", vec![0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0])]
+    #[case("1:50%:2", vec![1.0, 1.5, 2.0])]
+    #[case("2:50%:1", vec![2.0, 1.5, 1.0])]
+    #[case("1:0.5:2", vec![1.0, 1.5, 2.0])]
+    fn percent_step_f64(#[case] numstr: &str, #[case] numvec: Vec<f64>) {
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .with_percent_step(true)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("0:25%:100", vec![0, 25, 50, 75, 100])]
+    #[case("0:30%:100", vec![0, 30, 60, 90])]
+    #[case("100:50%:0", vec![100, 50, 0])]
+    #[case("0:200%:10", vec![0])]
+    #[should_panic]
+    #[case("0:10%:15", vec![])]
+    #[should_panic]
+    #[case("0:150%:15", vec![])]
+    fn percent_step_usize(#[case] numstr: &str, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRangeOptions::<usize>::new()
+                .with_percent_step(true)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("0:50%:200", vec![0, 100, 200])]
+    #[case("0:40%:250", vec![0, 100, 200])]
+    #[case("250:25%:50", vec![250, 200, 150, 100, 50])]
+    #[case("0:1%:200", (0..=200).step_by(2).collect())]
+    fn percent_step_u8(#[case] numstr: &str, #[case] numvec: Vec<u8>) {
+        assert_eq!(
+            NumberRangeOptions::<u8>::new()
+                .with_percent_step(true)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<u8>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("-100:10%:100", (-100..=100).step_by(20).collect())]
+    #[case("100:50%:-100", vec![100, 0, -100])]
+    #[case("-128:50%:126", vec![-128, -1, 126])]
+    #[should_panic]
+    #[case("-100:100%:100", vec![])]
+    fn percent_step_i8(#[case] numstr: &str, #[case] numvec: Vec<i8>) {
+        assert_eq!(
+            NumberRangeOptions::<i8>::new()
+                .with_percent_step(true)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<i8>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("0:-10%:100")]
+    #[case("100:-50%:0")]
+    #[should_panic]
+    fn percent_step_negative(#[case] numstr: &str) {
+        NumberRangeOptions::<i64>::new()
+            .with_percent_step(true)
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn percent_step_disabled() {
+        assert!(NumberRangeOptions::<f64>::new().parse("0:10%:100").is_err());
+    }
+
+    #[rstest]
+    fn parse_fn() {
+        assert_eq!(parse::<usize>("1,3:6").unwrap(), vec![1, 3, 4, 5, 6]);
+        assert_eq!(parse::<f64>("").unwrap(), vec![]);
+        assert!(parse::<usize>("1,-3").is_err());
+    }
+
+    #[rstest]
+    #[case("1_000:1_002", 3, vec![1000, 1001, 1002])]
+    #[case("12_345_678", 3, vec![12345678])]
+    #[case("1234_5678", 4, vec![12345678])]
+    #[case("1_2345_6789,10", 4, vec![123456789, 10])]
+    #[case("12_34_56", 2, vec![123456])]
+    #[should_panic]
+    #[case("123_45678", 4, vec![])]
+    #[should_panic]
+    #[case("12345_678", 3, vec![])]
+    #[should_panic]
+    #[case("_123", 3, vec![])]
+    #[should_panic]
+    #[case("1:12_34", 3, vec![])]
+    fn group_validation(#[case] numstr: &str, #[case] size: usize, #[case] numvec: Vec<u64>) {
+        assert_eq!(
+            NumberRangeOptions::<u64>::new()
+                .with_group_validation(true)
+                .with_group_size(size)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<u64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    fn group_validation_float() {
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .with_group_validation(true)
+                .parse("-1_000.125_5")
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            vec![-1000.1255]
+        );
+        assert!(NumberRangeOptions::<f64>::new().parse("1_0000.5").is_ok());
+    }
+
+    #[rstest]
+    #[case("[1,5]", vec![1, 2, 3, 4, 5])]
+    #[case("[1,5)", vec![1, 2, 3, 4])]
+    #[case("(1,5]", vec![2, 3, 4, 5])]
+    #[case("(1,5)", vec![2, 3, 4])]
+    #[case("[0,1)", vec![0])]
+    #[case("[0,0)", vec![])]
+    #[case("(1,2)", vec![])]
+    #[case("[1,3), 7, ( 8, 10 ],12:14", vec![1, 2, 7, 9, 10, 12, 13, 14])]
+    #[should_panic]
+    #[case("[1)", vec![])]
+    #[should_panic]
+    #[case("[1,5", vec![])]
+    fn math_intervals_usize(#[case] numstr: &str, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRangeOptions::<usize>::new()
+                .with_math_intervals(true)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("[0,0)", "")]
+    #[case("(3,3],1", "1")]
+    #[case("1,(1,2),[2,2]", "1,2:2")]
+    fn math_intervals_empty(#[case] numstr: &str, #[case] display: &str) {
+        let rng = NumberRangeOptions::<usize>::new()
+            .with_math_intervals(true)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.to_string(), display);
+    }
+
+    #[rstest]
+    #[case("[0.5,2.5]", vec![0.5, 1.5, 2.5])]
+    #[should_panic]
+    #[case("[0.5,2.5)", vec![])]
+    #[should_panic]
+    #[case("(0.5,2.5]", vec![])]
+    fn math_intervals_f64(#[case] numstr: &str, #[case] numvec: Vec<f64>) {
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .with_math_intervals(true)
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    #[case("1::10", Some(2), vec![1, 3, 5, 7, 9])]
+    #[case("1:3:10", Some(2), vec![1, 4, 7, 10])]
+    #[case("1:5", Some(2), vec![1, 2, 3, 4, 5])]
+    #[case("1::5", None, vec![1, 2, 3, 4, 5])]
+    #[case("10::1", Some(-3), vec![10, 7, 4, 1])]
+    fn default_step(#[case] numstr: &str, #[case] step: Option<i64>, #[case] numvec: Vec<i64>) {
+        let mut opts = NumberRangeOptions::<i64>::new();
+        if let Some(step) = step {
+            opts = opts.with_default_step(step);
+        }
+        assert_eq!(opts.parse(numstr).unwrap().collect::<Vec<i64>>(), numvec);
+    }
+
+    #[rstest]
+    #[case("nan:5")]
+    #[case("1:NaN")]
+    #[case("1:2:nan")]
+    #[case("-inf:5")]
+    #[case("1:0.5:inf")]
+    #[case("[1,inf)")]
+    fn non_finite_bounds(#[case] numstr: &str) {
+        let err = NumberRangeOptions::<f64>::new()
+            .with_math_intervals(true)
+            .parse(numstr)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::NonFiniteBound)
+        );
+    }
+
+    #[rstest]
+    #[case("1:nan:5")]
+    #[case("5:-NaN:1")]
+    #[case("0:nan%:1")]
+    fn nan_step(#[case] numstr: &str) {
+        let err = NumberRangeOptions::<f64>::new()
+            .with_percent_step(true)
+            .parse(numstr)
+            .unwrap_err();
+        assert!(format!("{:#}", err).to_lowercase().contains("nan"));
+    }
+
+    #[rstest]
+    fn nan_default_step() {
+        let opts = NumberRangeOptions::<f64>::new().with_default_step(f64::NAN);
+        assert!(opts.parse("1::5").is_err());
+    }
+
+    #[rstest]
+    #[case("pages=1:3,10", "pages=", vec![1, 2, 3, 10])]
+    #[case("1:3", "", vec![1, 2, 3])]
+    #[case("pages=", "pages=", vec![])]
+    #[should_panic]
+    #[case("page=1:3", "pages=", vec![])]
+    #[should_panic]
+    #[case(" pages=1:3", "pages=", vec![])]
+    fn parse_with_prefix(#[case] numstr: &str, #[case] prefix: &str, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRange::<usize>::default()
+                .parse_after(numstr, prefix)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    fn parse_from_bytes() {
+        let rng = NumberRange::<usize>::default()
+            .parse_bytes("1,3:5".as_bytes())
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![1, 3, 4, 5]);
+        let err = NumberRange::<usize>::default()
+            .parse_bytes(&[b'1', b',', 0xc3, 0x28])
+            .unwrap_err();
+        assert!(err.downcast_ref::<std::str::Utf8Error>().is_some());
+    }
+
+    #[rstest]
+    fn parse_from_os_str() {
+        let arg = std::ffi::OsString::from("1:3");
+        let rng = NumberRange::<usize>::default().parse_os_str(&arg).unwrap();
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![1, 2, 3]);
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn parse_from_os_str_invalid() {
+        use std::os::unix::ffi::OsStrExt;
+        let arg = std::ffi::OsStr::from_bytes(&[b'1', 0xff]);
+        assert!(NumberRange::<usize>::default().parse_os_str(arg).is_err());
+    }
+
+    #[rstest]
+    #[case("1,3:10,14:2:20", true)]
+    #[case("", true)]
+    #[case("4:1", true)]
+    #[case("10:-2:0", true)]
+    #[case("1,,3", false)]
+    #[case("1:2:3:4", false)]
+    #[case("1,a", false)]
+    #[case("1:-3", false)]
+    fn validate_usize(#[case] numstr: &str, #[case] valid: bool) {
+        let opts = NumberRangeOptions::<usize>::new();
+        let result = opts.validate(numstr);
+        assert_eq!(result.is_ok(), valid);
+        assert_eq!(opts.parse(numstr).is_ok(), valid);
+    }
+
+    #[rstest]
+    fn validate_error() {
+        let err = NumberRangeOptions::<f64>::new()
+            .validate("1,nan:4")
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::NonFiniteBound)
+        );
+        let err = NumberRangeOptions::<usize>::new()
+            .validate("1,x")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "x Not a Number");
+    }
+
+    #[rstest]
+    #[case("5", true, None, Some(8), vec![5, 6, 7, 8])]
+    #[case("5", false, None, Some(8), vec![5])]
+    #[case("5,1:2", true, None, Some(8), vec![5, 6, 7, 8, 1, 2])]
+    #[case("5", true, Some(2), None, vec![2, 3, 4, 5])]
+    #[case("5", true, Some(2), Some(8), vec![5, 6, 7, 8])]
+    #[case("5", true, None, None, vec![5])]
+    #[case("9", true, None, Some(8), vec![])]
+    fn single_as_range(
+        #[case] numstr: &str,
+        #[case] flag: bool,
+        #[case] start: Option<usize>,
+        #[case] end: Option<usize>,
+        #[case] numvec: Vec<usize>,
+    ) {
+        let mut opts = NumberRangeOptions::<usize>::new().with_single_as_range(flag);
+        opts.default_start = start;
+        opts.default_end = end;
+        assert_eq!(opts.parse(numstr).unwrap().collect::<Vec<usize>>(), numvec);
+    }
+
+    #[rstest]
+    fn same_range_decimal_sep() {
+        let err = NumberRangeOptions::<f64>::new()
+            .with_range_sep('.')
+            .parse("1.5")
+            .unwrap_err();
+        assert!(err.to_string().contains("decimal separator"));
+        assert!(NumberRangeOptions::<f64>::new()
+            .with_range_sep('.')
+            .parse("")
+            .is_err());
+        assert!(NumberRangeOptions::<f64>::new()
+            .with_range_sep('.')
+            .validate("1")
+            .is_err());
+        assert!(NumberRangeOptions::<f64>::new()
+            .with_range_sep(',')
+            .with_decimal_sep(',')
+            .with_list_sep(';')
+            .parse("1,5")
+            .is_err());
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .with_range_sep('.')
+                .with_range_sep_distinct(true)
+                .parse("1.3")
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            vec![1.0, 2.0, 3.0]
+        );
+    }
+
+    #[rstest]
+    #[case("1:+2:9", vec![1, 3, 5, 7, 9])]
+    #[case("+5", vec![5])]
+    #[case("+1:+3", vec![1, 2, 3])]
+    #[case("+1, +3", vec![1, 3])]
+    #[should_panic]
+    #[case("++5", vec![])]
+    #[should_panic]
+    #[case("+-5", vec![])]
+    #[should_panic]
+    #[case("+", vec![])]
+    fn plus_sign_usize(#[case] numstr: &str, #[case] numvec: Vec<usize>) {
+        assert_eq!(
+            NumberRangeOptions::<usize>::new()
+                .parse(numstr)
+                .unwrap()
+                .collect::<Vec<usize>>(),
+            numvec
+        );
+    }
+
+    #[rstest]
+    fn plus_sign_f64() {
+        assert_eq!(
+            NumberRangeOptions::<f64>::new()
+                .parse("-1:+.5:+1")
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            vec![-1.0, -0.5, 0.0, 0.5, 1.0]
+        );
+    }
+
+    #[rstest]
+    #[case("1,3:10,14", 3, true)]
+    #[case("1,3:10,14", 4, true)]
+    #[case("1,3:10,14", 2, false)]
+    #[case("1,1,1,1,1,1,1,1", 5, false)]
+    #[case("", 0, true)]
+    fn max_segments(#[case] numstr: &str, #[case] max: usize, #[case] ok: bool) {
+        let opts = || NumberRangeOptions::<usize>::new().with_max_segments(max);
+        assert_eq!(opts().validate(numstr).is_ok(), ok);
+        match opts().parse(numstr) {
+            Ok(_) => assert!(ok),
+            Err(err) => {
+                assert!(!ok);
+                assert_eq!(
+                    err.downcast_ref::<ParseError>(),
+                    Some(&ParseError::TooManySegments)
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("1,bad,3:5", vec![1, 3, 4, 5], 1)]
+    #[case("1:x,2:3:4:5,7", vec![7], 2)]
+    #[case("1,3:5", vec![1, 3, 4, 5], 0)]
+    #[case("", vec![], 0)]
+    fn partial_parse(#[case] numstr: &str, #[case] expected: Vec<i64>, #[case] errors: usize) {
+        let (rng, errs) = NumberRange::<i64>::default().parse_partial(numstr);
+        assert_eq!(rng.original(), numstr);
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+        assert_eq!(errs.len(), errors);
+    }
+
+    #[rstest]
+    #[case("1, 3:10 , 14", vec!["1", " 3:10 ", " 14"])]
+    #[case("5", vec!["5"])]
+    #[case("  ", vec![])]
+    fn raw_tokens(#[case] numstr: &str, #[case] expected: Vec<&str>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.tokens(false), expected);
+        let trimmed: Vec<&str> = expected.iter().map(|t| t.trim()).collect();
+        assert_eq!(rng.tokens(true), trimmed);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[rstest]
+    fn parse_into_array() {
+        let numbers = NumberRangeOptions::<i32>::new()
+            .parse_into_array::<4>("1,3:5,8:-1:6")
+            .unwrap();
+        assert_eq!(numbers.capacity(), 4);
+        assert_eq!(
+            numbers,
+            [
+                Number::Single(1),
+                Number::Range(3, 1, 5),
+                Number::Range(8, -1, 6)
+            ]
+        );
+        let err = NumberRangeOptions::<i32>::new()
+            .parse_into_array::<4>("1,2,3,4,5")
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::TooManySegments)
+        );
+    }
+
+    #[rstest]
+    #[case("1:5", vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)])]
+    #[case("8:-3:1,0", vec![(0, 8), (1, 5), (2, 2), (3, 0)])]
+    #[case("", vec![])]
+    fn progress(#[case] numstr: &str, #[case] expected: Vec<(usize, i64)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        let mut calls = Vec::new();
+        rng.for_each_with_progress(expected.len(), |i, n, v| calls.push((i, n, v)));
+        let expected: Vec<(usize, usize, i64)> = expected
+            .iter()
+            .map(|(i, v)| (*i, expected.len(), *v))
+            .collect();
+        assert_eq!(calls, expected);
+    }
+
+    #[rstest]
+    fn progress_past_hint() {
+        let rng = NumberRange::<i64>::default().parse_str("1:5").unwrap();
+        let mut calls = Vec::new();
+        rng.for_each_with_progress(2, |i, n, v| calls.push((i, n, v)));
+        assert_eq!(
+            calls,
+            vec![(0, 2, 1), (1, 2, 2), (2, 2, 3), (3, 2, 4), (4, 2, 5)]
+        );
+    }
+
+    #[rstest]
+    #[case("5:5", vec![5])]
+    #[case("5:2:5", vec![5])]
+    #[case("5:-2:5", vec![5])]
+    #[case("0:0", vec![0])]
+    fn degenerate_range(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+        let rng = NumberRange::<f64>::default().parse_str(numstr).unwrap();
+        let expected: Vec<f64> = expected.into_iter().map(|v| v as f64).collect();
+        assert_eq!(rng.collect::<Vec<f64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("5:0:5")]
+    #[case("1:0:10")]
+    #[case("10:-0:1")]
+    #[case("1:0.0:10")]
+    fn zero_step(#[case] numstr: &str) {
+        let err = NumberRange::<f64>::default().parse_str(numstr).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::ZeroStep)
+        );
+    }
+
+    #[rstest]
+    #[case("5:0:5")]
+    #[case("10:-0:1")]
+    #[case("0:0%:100")]
+    fn zero_step_unsigned(#[case] numstr: &str) {
+        let err = NumberRangeOptions::<usize>::new()
+            .with_percent_step(true)
+            .parse(numstr)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::ZeroStep)
+        );
+    }
+
+    #[rstest]
+    fn zero_default_step() {
+        let err = NumberRangeOptions::<i32>::new()
+            .with_default_step(0)
+            .parse("1::5")
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::ZeroStep)
+        );
+        let rng = NumberRangeOptions::<i32>::new()
+            .with_percent_step(true)
+            .parse("5:10%:5")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i32>>(), vec![5]);
+    }
+
+    struct Selection {
+        pages: OwnedNumberRange<usize>,
+    }
+
+    #[rstest]
+    fn owned_range() {
+        let selection = {
+            let input = String::from("1,3:5");
+            Selection {
+                pages: NumberRange::default().parse_owned(&input).unwrap(),
+            }
+        };
+        assert_eq!(selection.pages.original(), "1,3:5");
+        assert_eq!(selection.pages.collect::<Vec<usize>>(), vec![1, 3, 4, 5]);
+        assert!(NumberRange::<usize>::default().parse_owned("1:x").is_err());
+    }
+
+    #[rstest]
+    #[case("1,000-2,000", (1000..=2000).collect())]
+    #[case("1,000 - 1,002/2,000", vec![1000, 1001, 1002, 2000])]
+    #[case("999-1,001", vec![999, 1000, 1001])]
+    fn grouped_range_bounds(#[case] numstr: &str, #[case] expected: Vec<usize>) {
+        let rng: Vec<usize> = NumberRangeOptions::new()
+            .with_list_sep('/')
+            .with_range_sep('-')
+            .with_group_sep(',')
+            .with_whitespace(true)
+            .parse(numstr)
+            .unwrap()
+            .collect();
+        assert_eq!(rng, expected);
+    }
+
+    #[rstest]
+    #[case("-2:1:2", vec![-2.0, -1.0, 0.0, 1.0, 2.0])]
+    #[case("-0:0", vec![0.0])]
+    #[case("0:-0", vec![0.0])]
+    #[case("-0:2", vec![0.0, 1.0, 2.0])]
+    #[case("-0:-1:-2", vec![0.0, -1.0, -2.0])]
+    #[case("-1:0.5:0", vec![-1.0, -0.5, 0.0])]
+    #[case("1:-0.5:-0", vec![1.0, 0.5, 0.0])]
+    fn float_through_zero(#[case] numstr: &str, #[case] expected: Vec<f64>) {
+        let rng: Vec<f64> = NumberRange::<f64>::default()
+            .parse_str(numstr)
+            .unwrap()
+            .collect();
+        assert_eq!(rng, expected);
+    }
+
+    #[rstest]
+    fn float_negative_zero_start() {
+        let rng: Vec<f64> = NumberRange::<f64>::default()
+            .parse_str("-0:1")
+            .unwrap()
+            .collect();
+        assert!(rng[0].is_sign_negative());
+        assert_eq!(rng, vec![0.0, 1.0]);
+    }
+
+    #[rstest]
+    #[case("first:5", vec![1, 2, 3, 4, 5])]
+    #[case("18:last", vec![18, 19, 20])]
+    #[case("first,last", vec![1, 20])]
+    #[case("last:-5:first", vec![20, 15, 10, 5])]
+    fn keywords(#[case] numstr: &str, #[case] expected: Vec<usize>) {
+        let rng: Vec<usize> = NumberRangeOptions::new()
+            .with_default_start(1)
+            .with_default_end(20)
+            .with_keywords(true)
+            .parse(numstr)
+            .unwrap()
+            .collect();
+        assert_eq!(rng, expected);
+    }
+
+    #[rstest]
+    #[case("first:5")]
+    #[case("10:last")]
+    #[should_panic]
+    fn keywords_without_default(#[case] numstr: &str) {
+        NumberRangeOptions::<usize>::new()
+            .with_keywords(true)
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn keywords_disabled() {
+        let opts = NumberRangeOptions::<usize>::new()
+            .with_default_start(1)
+            .with_default_end(20);
+        assert!(opts.parse("first:5").is_err());
+    }
+
+    #[rstest]
+    #[case("1:3,8,10:12", vec![vec![1, 2, 3], vec![8], vec![10, 11, 12]])]
+    #[case("5:-2:1,4:1", vec![vec![5, 3, 1], vec![]])]
+    #[case("", vec![])]
+    fn segment_iters(#[case] numstr: &str, #[case] expected: Vec<Vec<i64>>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        let segments: Vec<Vec<i64>> = rng.segment_iters().map(|seg| seg.collect()).collect();
+        assert_eq!(segments, expected);
+    }
+
+    #[rstest]
+    #[case("1.0", "1.0")]
+    #[case("3, 2.5:4", "2.5")]
+    #[case("1:0.5:4", "0.5")]
+    #[case("1:-.5:4", "-.5")]
+    fn fractional_for_integer(#[case] numstr: &str, #[case] token: &str) {
+        let err = NumberRange::<i64>::default().parse_str(numstr).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::FractionalForInteger {
+                token: token.to_string()
+            })
+        );
+        assert!(NumberRange::<f64>::default().parse_str(numstr).is_ok());
+    }
+
+    #[rstest]
+    fn fractional_for_unsigned() {
+        let err = NumberRange::<usize>::default()
+            .parse_str("1:1.5:5")
+            .unwrap_err();
+        assert!(err.to_string().contains("float type"));
+        let err = NumberRange::<usize>::default()
+            .parse_str("1.x")
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<ParseError>(), None);
+    }
+
+    #[rstest]
+    #[case("1:3", 2, "1:3,1:3", vec![1, 2, 3, 1, 2, 3])]
+    #[case("5,1:-2:-1", 3, "5,1:-2:-1,5,1:-2:-1,5,1:-2:-1", vec![5, 1, -1, 5, 1, -1, 5, 1, -1])]
+    #[case("1:3", 1, "1:3", vec![1, 2, 3])]
+    #[case("1:3", 0, "", vec![])]
+    fn repeat(
+        #[case] numstr: &str,
+        #[case] times: usize,
+        #[case] display: &str,
+        #[case] expected: Vec<i64>,
+    ) {
+        let rng = NumberRange::<i64>::default()
+            .parse_str(numstr)
+            .unwrap()
+            .repeat(times);
+        assert_eq!(format!("{}", rng), display);
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case(' ', "1 200:1 203", false)]
+    #[case(' ', " 1 200 : 1 203 ", false)]
+    #[case(' ', "1 200:1 203", true)]
+    #[case('\u{2009}', "1\u{2009}200:1\u{2009}203", false)]
+    #[case('\u{202f}', "1\u{202f}200:1\u{202f}203", true)]
+    fn space_group_sep(#[case] sep: char, #[case] numstr: &str, #[case] whitespace: bool) {
+        let rng = NumberRangeOptions::<u32>::new()
+            .with_group_sep(sep)
+            .with_group_validation(true)
+            .with_whitespace(whitespace)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.to_grouped_string(), format!("1{0}200:1{0}203", sep));
+        assert_eq!(rng.collect::<Vec<u32>>(), vec![1200, 1201, 1202, 1203]);
+    }
+
+    #[rstest]
+    #[case("1:3,8,10:2:20", 2)]
+    #[case("1,8,20:-2:10,5", 1)]
+    #[case("", 0)]
+    fn fold_segments(#[case] numstr: &str, #[case] ranges: usize) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        let count = rng.fold_segments(0, |count, n| match n {
+            Number::Single(_) => count,
+            _ => count + 1,
+        });
+        assert_eq!(count, ranges);
+    }
+
+    #[rstest]
+    #[case("3x5,2x9", vec![5, 5, 5, 9, 9], "3x5,2x9")]
+    #[case("1:3, 2x-1", vec![1, 2, 3, -1, -1], "1:3,2x-1")]
+    #[case("0x4,4", vec![4], "0x4,4")]
+    fn repeat_marker(#[case] numstr: &str, #[case] expected: Vec<i64>, #[case] display: &str) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(format!("{}", rng), display);
+        assert_eq!(rng.total_count(), expected.len());
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("3x5x2")]
+    #[case("-3x5")]
+    #[case("3x1:5")]
+    #[should_panic]
+    fn repeat_marker_error(#[case] numstr: &str) {
+        NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn repeat_marker_segments() {
+        let rng = NumberRangeOptions::<u8>::new()
+            .with_repeat_marker('*')
+            .parse("2*7,1:3")
+            .unwrap();
+        assert_eq!(rng.to_string(), "2*7,1:3");
+        assert_eq!(rng.nth_value(1), Some(7));
+        assert_eq!(rng.nth_value(2), Some(1));
+        assert_eq!(rng.iter_sorted().collect::<Vec<u8>>(), vec![1, 2, 3, 7, 7]);
+        assert_eq!(
+            rng.to_config(),
+            vec![(7, None, 7), (7, None, 7), (1, None, 3)]
+        );
+    }
+
+    #[rstest]
+    #[case("-5:5", 100, (95..=105).collect())]
+    #[case("-100,0,10:-5:0", 100, vec![0, 100, 110, 105, 100])]
+    #[case("-273:-270", 273, vec![0, 1, 2, 3])]
+    #[case("10,20", -10, vec![0, 10])]
+    #[case("65435", 100, vec![65535])]
+    fn offset_values(#[case] numstr: &str, #[case] offset: i64, #[case] expected: Vec<u16>) {
+        let rng = NumberRangeOptions::<u16>::new()
+            .with_offset(offset)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u16>>(), expected);
+    }
+
+    #[rstest]
+    #[case("-101:0")]
+    #[case("65436")]
+    #[case("1.5")]
+    #[should_panic]
+    fn offset_out_of_range(#[case] numstr: &str) {
+        NumberRangeOptions::<u16>::new()
+            .with_offset(100)
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn offset_wide_values() {
+        let rng = NumberRangeOptions::<u64>::new()
+            .with_offset(-1)
+            .parse("18446744073709551615")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u64>>(), vec![u64::MAX - 1]);
+        let rng = NumberRangeOptions::<u128>::new()
+            .with_offset(5)
+            .parse("-5,340282366920938463463374607431768211450")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u128>>(), vec![0, u128::MAX]);
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_offset(10)
+            .parse("1.5:0.5:2.5")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<f64>>(), vec![11.5, 12.0, 12.5]);
+    }
+
+    #[rstest]
+    fn offset_defaults() {
+        let rng = NumberRangeOptions::<u16>::new()
+            .with_offset(100)
+            .with_default_end(103)
+            .parse("-2:")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u16>>(), vec![98, 99, 100, 101, 102, 103]);
+    }
+
+    #[rstest]
+    #[case("5:2:5", false, vec![5])]
+    #[case("5:5", false, vec![5])]
+    #[case("5:-2:5", false, vec![5])]
+    #[case("5:2:5", true, vec![])]
+    #[case("5:5", true, vec![])]
+    #[case("5:-2:5", true, vec![])]
+    #[case("5,5:6", true, vec![5, 5, 6])]
+    fn empty_on_equal_bounds(
+        #[case] numstr: &str,
+        #[case] empty: bool,
+        #[case] expected: Vec<u32>,
+    ) {
+        let rng = NumberRangeOptions::<u32>::new()
+            .with_empty_on_equal_bounds(empty)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u32>>(), expected);
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_empty_on_equal_bounds(empty)
+            .parse(numstr)
+            .unwrap();
+        let expected: Vec<f64> = expected.into_iter().map(f64::from).collect();
+        assert_eq!(rng.collect::<Vec<f64>>(), expected);
+    }
+
+    #[rstest]
+    fn empty_on_equal_bounds_display() {
+        let rng = NumberRangeOptions::<u32>::new()
+            .with_empty_on_equal_bounds(true)
+            .parse("1:3,5:5,7:2:7,9")
+            .unwrap();
+        assert_eq!(rng.numbers.len(), 2);
+        assert_eq!(rng.to_string(), "1:3,9");
+    }
+
+    #[rstest]
+    #[case("1;5,1:5", vec![1, 2, 3, 4, 1, 2, 3, 4, 5])]
+    #[case("0;2;10,10:-2:6", vec![0, 2, 4, 6, 8, 10, 8, 6])]
+    #[case("5;-1;1", vec![5, 4, 3, 2])]
+    #[case("5;5,5;6,7", vec![5, 7])]
+    #[case("3;", vec![3, 4, 5, 6, 7, 8, 9])]
+    #[case("0;3;10,10;-4;0", vec![0, 3, 6, 9, 10, 6, 2])]
+    fn exclusive_range_sep(#[case] numstr: &str, #[case] expected: Vec<usize>) {
+        let rng = NumberRangeOptions::<usize>::new()
+            .with_exclusive_range_sep(';')
+            .with_default_end(10)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<usize>>(), expected);
+    }
+
+    #[rstest]
+    #[case("0;0.5;2", vec![0.0, 0.5, 1.0, 1.5])]
+    #[case("0;0.25;1,2;-0.5;1", vec![0.0, 0.25, 0.5, 0.75, 2.0, 1.5])]
+    #[case("-1;0.75;2", vec![-1.0, -0.25, 0.5, 1.25])]
+    #[case("1;1.5", vec![1.0])]
+    #[case("1.5;1.5", vec![])]
+    fn exclusive_range_sep_float(#[case] numstr: &str, #[case] expected: Vec<f64>) {
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_exclusive_range_sep(';')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<f64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1;5:6")]
+    #[case("1;2;3;4")]
+    #[should_panic]
+    fn exclusive_range_sep_error(#[case] numstr: &str) {
+        NumberRangeOptions::<usize>::new()
+            .with_exclusive_range_sep(';')
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case("1:5i,7:10e", vec![1, 2, 3, 4, 5, 7, 8, 9])]
+    #[case("1:5, 1:5i, 1:5e ", vec![1, 2, 3, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4])]
+    #[case("1;5i,1;5e,1;5", vec![1, 2, 3, 4, 5, 1, 2, 3, 4, 1, 2, 3, 4])]
+    #[case("0:3:9e,0:3:9i", vec![0, 3, 6, 0, 3, 6, 9])]
+    #[case("5:-1:2e,3:3e", vec![5, 4, 3])]
+    #[case("3:e", vec![3, 4, 5, 6, 7, 8, 9])]
+    fn inclusivity_suffixes(#[case] numstr: &str, #[case] expected: Vec<usize>) {
+        let rng = NumberRangeOptions::<usize>::new()
+            .with_inclusivity_suffixes(true)
+            .with_exclusive_range_sep(';')
+            .with_default_end(10)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<usize>>(), expected);
+    }
+
+    #[rstest]
+    fn inclusivity_suffixes_float() {
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_inclusivity_suffixes(true)
+            .parse("0:0.5:2e,0:0.5:1i,3:0.75:4e")
+            .unwrap();
+        assert_eq!(
+            rng.collect::<Vec<f64>>(),
+            vec![0.0, 0.5, 1.0, 1.5, 0.0, 0.5, 1.0, 3.0, 3.75]
+        );
+    }
+
+    #[rstest]
+    #[case("5e")]
+    #[case("5i")]
+    #[case("1:5x")]
+    #[case("1:5ie")]
+    #[should_panic]
+    fn inclusivity_suffixes_error(#[case] numstr: &str) {
+        NumberRangeOptions::<usize>::new()
+            .with_inclusivity_suffixes(true)
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn inclusivity_suffixes_off() {
+        assert!(NumberRangeOptions::<usize>::new().parse("1:5e").is_err());
+    }
+
+    #[rstest]
+    #[case("(5):0", vec![-5.0, -4.0, -3.0, -2.0, -1.0, 0.0])]
+    #[case("(1.5); ( 2 )", vec![-1.5, -2.0])]
+    #[case("0:(0.5):(1)", vec![0.0, -0.5, -1.0])]
+    #[case("(1,000.5)", vec![-1000.5])]
+    fn accounting_negatives(#[case] numstr: &str, #[case] expected: Vec<f64>) {
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_list_sep(';')
+            .with_group_sep(',')
+            .with_group_validation(true)
+            .with_accounting_negatives(true)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<f64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("(5)", false)]
+    #[case("(-5)", true)]
+    #[case("(5", true)]
+    #[should_panic]
+    fn accounting_negatives_error(#[case] numstr: &str, #[case] flag: bool) {
+        NumberRangeOptions::<i64>::new()
+            .with_accounting_negatives(flag)
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case("1.0", "1.0")]
+    #[case("1:0.5:3", "0.5")]
+    #[case("1,2,3:4.", "4.")]
+    #[case("1:-.5:0", "-.5")]
+    fn decimals_disabled(#[case] numstr: &str, #[case] token: &str) {
+        let err = NumberRangeOptions::<i64>::new()
+            .with_decimals(false)
+            .parse(numstr)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::FractionalForInteger {
+                token: token.to_string()
+            })
+        );
+    }
+
+    #[rstest]
+    fn decimals_disabled_separators() {
+        let opts = || {
+            NumberRangeOptions::<u32>::new()
+                .with_decimals(false)
+                .with_decimal_sep(',')
+                .with_list_sep(';')
+        };
+        assert_eq!(
+            opts().parse("1;2:4").unwrap().collect::<Vec<u32>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert!(opts().parse("1,5").is_err());
+        assert!(opts().with_offset(2).parse("1,5").is_err());
+    }
+
+    #[rstest]
+    #[case("001:010", Some(3), "001:010")]
+    #[case("1:10", None, "1:10")]
+    #[case("0", None, "0")]
+    #[case("7,0010,-05:5", Some(4), "0007,0010,-005:0005")]
+    #[case("01:2:20", Some(2), "01:02:20")]
+    #[case("", None, "")]
+    fn padding(#[case] numstr: &str, #[case] width: Option<usize>, #[case] padded: &str) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.detected_width(), width);
+        assert_eq!(rng.to_padded_string(width.unwrap_or(0)), padded);
+    }
+
+    #[rstest]
+    #[case("0:253", "254:255")]
+    #[case("10:20,5,30:2:34", "0:4,6:9,21:29,31:2:33,35:255")]
+    #[case("0:255", "")]
+    #[case("", "0:255")]
+    #[case("255,0", "1:254")]
+    #[case("3,3:-1:1", "0,4:255")]
+    fn complement_u8(#[case] numstr: &str, #[case] complement: &str) {
+        let rng = NumberRange::<u8>::default().parse_str(numstr).unwrap();
+        assert_eq!(format!("{}", rng.complement_in_type()), complement);
+    }
+
+    #[rstest]
+    fn complement_stepped() {
+        let rng = NumberRange::<u32>::default()
+            .parse_str("0:2:4294967294")
+            .unwrap();
+        let complement = rng.complement_in_type();
+        assert_eq!(format!("{}", complement), "1:2:4294967295");
+        assert_eq!(complement.total_count(), 1 << 31);
+        let rng = NumberRange::<i8>::default()
+            .parse_str("-128:127:127")
+            .unwrap();
+        assert_eq!(format!("{}", rng.complement_in_type()), "-127:-2,0:125,127");
+    }
+
+    #[rstest]
+    fn iter_till_type_bounds() {
+        let rng = NumberRange::<u8>::default()
+            .parse_str("253:255,2:-1:0")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u8>>(), vec![253, 254, 255, 2, 1, 0]);
+        let rng = NumberRange::<i8>::default()
+            .parse_str("125:127,-126:-1:-128,-1:100:127")
+            .unwrap();
+        assert_eq!(
+            rng.collect::<Vec<i8>>(),
+            vec![125, 126, 127, -126, -127, -128, -1, 99]
+        );
+    }
+
+    #[rstest]
+    #[case("100±5", vec![95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105])]
+    #[case("1 ± 1, 5+-0", vec![0, 1, 2, 5])]
+    #[case("5,8±1", vec![5, 7, 8, 9])]
+    #[case("5±5", vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10])]
+    fn pm_marker(#[case] numstr: &str, #[case] expected: Vec<u32>) {
+        let rng = NumberRangeOptions::<u32>::new()
+            .with_pm_marker('±')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u32>>(), expected);
+    }
+
+    #[rstest]
+    #[case("2±5")]
+    #[case("5±-1")]
+    #[case("5±1±1")]
+    #[case("±1")]
+    #[should_panic]
+    fn pm_marker_error(#[case] numstr: &str) {
+        NumberRangeOptions::<u32>::new()
+            .with_pm_marker('±')
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case("250±10", false)]
+    #[case("10±20", false)]
+    #[case("127±1", true)]
+    #[case("-120±10", true)]
+    #[case("100±100", true)]
+    fn pm_marker_bounds(#[case] numstr: &str, #[case] signed: bool) {
+        let result = if signed {
+            let opts = NumberRangeOptions::<i8>::new().with_pm_marker('±');
+            opts.parse(numstr).map(|_| ())
+        } else {
+            let opts = NumberRangeOptions::<u8>::new().with_pm_marker('±');
+            opts.parse(numstr).map(|_| ())
+        };
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn pm_marker_signed() {
+        let rng = NumberRangeOptions::<i32>::new()
+            .with_pm_marker('±')
+            .parse("2±3")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i32>>(), vec![-1, 0, 1, 2, 3, 4, 5]);
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_pm_marker('±')
+            .parse("1.5±0.5")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<f64>>(), vec![1.0, 2.0]);
+    }
+
+    #[rstest]
+    #[case("1:5", "3:7", "1:5,3:7", vec![1, 2, 3, 4, 5, 3, 4, 5, 6, 7])]
+    #[case("4,2", "2,4", "4,2,2,4", vec![4, 2, 2, 4])]
+    #[case("", "1:2", "1:2", vec![1, 2])]
+    #[case("9:-3:3", "", "9:-3:3", vec![9, 6, 3])]
+    fn append_all_keeps_duplicates(
+        #[case] first: &str,
+        #[case] second: &str,
+        #[case] display: &str,
+        #[case] expected: Vec<i64>,
+    ) {
+        let mut rng = NumberRange::<i64>::default().parse_str(first).unwrap();
+        let other = NumberRange::<i64>::default().parse_str(second).unwrap();
+        rng.append_all(&other);
+        assert_eq!(format!("{}", rng), display);
+        assert_eq!(rng.original(), "");
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1 3-5", vec![1, 3, 4, 5])]
+    #[case("1 3 5-7", vec![1, 3, 5, 6, 7])]
+    #[case("  2\n\t8-10  ", vec![2, 8, 9, 10])]
+    #[case("1-2-7 9", vec![1, 3, 5, 7, 9])]
+    #[case(" ", vec![])]
+    fn whitespace_list_sep(#[case] numstr: &str, #[case] expected: Vec<usize>) {
+        let rng = NumberRangeOptions::<usize>::new()
+            .with_list_sep(' ')
+            .with_range_sep('-')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.tokens(false).len(), rng.numbers.len());
+        assert_eq!(rng.collect::<Vec<usize>>(), expected);
+    }
+
+    #[rstest]
+    #[case("0:5:20", 7, Some(5))]
+    #[case("0:5:20", 8, Some(10))]
+    #[case("0:5:20", -8, Some(0))]
+    #[case("0:5:22", 30, Some(20))]
+    #[case("1:3:10", 5, Some(4))]
+    #[case("20:-5:0", 12, Some(10))]
+    #[case("21:-4:10", 9, Some(13))]
+    #[case("10:-3:1", 3, Some(4))]
+    #[case("7", 100, Some(7))]
+    #[case("2x7", 1, Some(7))]
+    #[case("", 1, None)]
+    #[case("1:5,7", 1, None)]
+    #[case("5:1", 3, None)]
+    fn snap_to_grid(#[case] numstr: &str, #[case] value: i64, #[case] snapped: Option<i64>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.snap(value), snapped);
+    }
+
+    #[rstest]
+    #[case("5,1,3", vec![1, 3, 5])]
+    #[case("1:3,2:4", vec![1, 2, 2, 3, 3, 4])]
+    #[case("10:-3:1,4,7:8", vec![1, 4, 4, 7, 7, 8, 10])]
+    #[case("", vec![])]
+    fn collect_sorted_keeps_duplicates(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.collect_sorted(), expected);
+    }
+
+    #[rstest]
+    #[case("1:|5|:9", vec![1, 3, 5, 7, 9])]
+    #[case("9:|3|:1", vec![9, 5, 1])]
+    #[case("0:|2|:10, 4:|1|:4", vec![0, 10, 4])]
+    #[case("-6: |4| :3", vec![-6, -3, 0, 3])]
+    #[case("1:2:5", vec![1, 3, 5])]
+    fn count_delimiters(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_count_delimiters('|', '|')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1:|4|:9")]
+    #[case("1:|0|:9")]
+    #[case("1:|1|:9")]
+    #[case("5:|3|:5")]
+    #[case("1:|-3|:9")]
+    #[case("1:|x|:9")]
+    #[should_panic]
+    fn count_delimiters_error(#[case] numstr: &str) {
+        NumberRangeOptions::<i64>::new()
+            .with_count_delimiters('|', '|')
+            .parse(numstr)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn count_delimiters_float() {
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_count_delimiters('[', ']')
+            .parse("0:[5]:1")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<f64>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[rstest]
+    #[case("-100:[3]:100", vec![-100, 0, 100])]
+    #[case("100:[5]:-100", vec![100, 50, 0, -50, -100])]
+    #[case("-63:[2]:64", vec![-63, 64])]
+    #[case("-100:auto:100", vec![-100, 0, 100])]
+    #[should_panic]
+    #[case("-128:[2]:127", vec![])]
+    fn count_delimiters_i8(#[case] numstr: &str, #[case] expected: Vec<i8>) {
+        let rng = NumberRangeOptions::<i8>::new()
+            .with_count_delimiters('[', ']')
+            .with_target_count(3)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i8>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1:5", "5,4,3,2,1,1", true)]
+    #[case("1:2:9", "9:-2:1,3x5", true)]
+    #[case("1:10", "1:5,6:10", true)]
+    #[case("1:10", "1:4,6:10", false)]
+    #[case("0:2000000000", "0:2:2000000000,1:2:2000000000", true)]
+    #[case("0:2:2000000000", "0:4:2000000000,1:4:2000000000", false)]
+    #[case("", "5:1", true)]
+    #[case("1", "", false)]
+    fn same_set(#[case] first: &str, #[case] second: &str, #[case] same: bool) {
+        let opts = || NumberRangeOptions::<i64>::new().with_repeat_marker('x');
+        let rng1 = opts().parse(first).unwrap();
+        let rng2 = opts().parse(second).unwrap();
+        assert_eq!(rng1.same_set_as(&rng2), same);
+        assert_eq!(rng2.same_set_as(&rng1), same);
+    }
+
+    #[rstest]
+    #[case(
+        "9:19:50000000,4:17:60000000,5:13:70000000,2:11:80000000,89999995:-7:1,0:3:100000000",
+        true
+    )]
+    #[case("0:3:49999998,50000001:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:50000000", true)]
+    #[case(
+        "0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:50000000,8",
+        true
+    )]
+    #[case(
+        "0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:50000000,7",
+        false
+    )]
+    #[case(
+        "0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:40000000",
+        false
+    )]
+    fn same_set_entangled(#[case] other: &str, #[case] same: bool) {
+        let rng1 = NumberRange::<i64>::default()
+            .parse_str("0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:50000000")
+            .unwrap();
+        let rng2 = NumberRange::<i64>::default().parse_str(other).unwrap();
+        assert_eq!(rng1.same_set_as(&rng2), same);
+        assert_eq!(rng2.same_set_as(&rng1), same);
+    }
+
+    #[rstest]
+    #[case(b"1:3,10", vec![1, 2, 3, 10])]
+    #[case(b"  5:-2:1 \n", vec![5, 3, 1])]
+    #[case(b"", vec![])]
+    fn parse_reader(#[case] bytes: &[u8], #[case] expected: Vec<i64>) {
+        let rng = NumberRange::<i64>::default()
+            .parse_reader(std::io::Cursor::new(bytes))
+            .unwrap();
+        assert_eq!(rng.original(), std::str::from_utf8(bytes).unwrap());
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    fn parse_reader_error() {
+        let err = NumberRange::<i64>::default()
+            .parse_reader(std::io::Cursor::new(b"1,\xff"))
+            .unwrap_err();
+        assert!(err.downcast_ref::<std::io::Error>().is_some());
+        let err = NumberRange::<i64>::default()
+            .parse_reader(std::io::Cursor::new(b"1,x"))
+            .unwrap_err();
+        assert!(err.downcast_ref::<std::io::Error>().is_none());
+    }
+
+    #[rstest]
+    #[case("5", Some(5))]
+    #[case("1,2", None)]
+    #[case("3:3", Some(3))]
+    #[case("3:5:4", Some(3))]
+    #[case("9:-4:6", Some(9))]
+    #[case("1x7", Some(7))]
+    #[case("2x7", None)]
+    #[case("1:2", None)]
+    #[case("5:1", None)]
+    #[case("", None)]
+    fn single_value(#[case] numstr: &str, #[case] expected: Option<i64>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.as_single(), expected);
+    }
+
+    #[rstest]
+    #[case("10:1", vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1], "10:-1:1")]
+    #[case("3:-2", vec![3, 2, 1, 0, -1, -2], "3:-1:-2")]
+    #[case("4::2,7", vec![4, 3, 2, 7], "4:-1:2,7")]
+    #[case("1:5", vec![], "1:-1:5")]
+    #[case("1:2:5,5:-2:1", vec![1, 3, 5, 5, 3, 1], "1:2:5,5:-2:1")]
+    fn descending_signed(#[case] numstr: &str, #[case] expected: Vec<i64>, #[case] display: &str) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_descending(true)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(format!("{}", rng), display);
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("5:0", vec![5, 4, 3, 2, 1, 0])]
+    #[case("3:3", vec![3])]
+    #[case("2:4", vec![])]
+    #[case("6::4", vec![6, 5, 4])]
+    fn descending_unsigned(#[case] numstr: &str, #[case] expected: Vec<u8>) {
+        let rng = NumberRangeOptions::<u8>::new()
+            .with_descending(true)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u8>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1:4", vec![(1, 2), (2, 3), (3, 4)])]
+    #[case("1,5:-2:1,10", vec![(1, 5), (5, 3), (3, 1), (1, 10)])]
+    #[case("1:2", vec![(1, 2)])]
+    #[case("7", vec![])]
+    #[case("", vec![])]
+    fn pairwise_values(#[case] numstr: &str, #[case] pairs: Vec<(i64, i64)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.pairwise().collect::<Vec<(i64, i64)>>(), pairs);
+    }
+
+    #[rstest]
+    #[case("1:5", true)]
+    #[case("1:6", false)]
+    #[case("1:2:9,0:-2:-8", true)]
+    #[case("1:2:11", false)]
+    #[case("1:3,4:6,7:9,10:12,13:15,16:18", true)]
+    #[case("5x1", true)]
+    #[case("6x1", false)]
+    #[case("1:300:1000", true)]
+    #[case("100:-1:0", false)]
+    fn max_segment_count(#[case] numstr: &str, #[case] allowed: bool) {
+        let parsed = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .with_max_segment_count(5)
+            .parse(numstr);
+        assert_eq!(parsed.is_ok(), allowed);
+    }
+
+    #[rstest]
+    fn max_segment_count_zero() {
+        let opts = || NumberRangeOptions::<f64>::new().with_max_segment_count(0);
+        assert!(opts().parse("").is_ok());
+        assert!(opts().parse("5:1").is_ok());
+        assert!(opts().parse("1.5").is_err());
+        let opts = || NumberRangeOptions::<u8>::new().with_max_segment_count(1000);
+        assert!(opts().parse("0:255").is_ok());
+    }
+
+    #[rstest]
+    fn max_segment_count_type_bounds() {
+        let opts = || NumberRangeOptions::<i64>::new().with_max_segment_count(10);
+        assert!(opts()
+            .parse("-9223372036854775808:9223372036854775807")
+            .is_err());
+        assert!(opts()
+            .parse("9223372036854775807:-1:-9223372036854775808")
+            .is_err());
+        assert!(opts()
+            .parse("-9223372036854775808:9223372036854775807:9223372036854775807")
+            .is_ok());
+    }
+
+    #[rstest]
+    #[case("1:3,5,7:9", vec![(1, 3), (5, 5), (7, 9)])]
+    #[case("7:9,1:3,5", vec![(1, 3), (5, 5), (7, 9)])]
+    #[case("1:2:7,2", vec![(1, 3), (5, 5), (7, 7)])]
+    #[case("10:-1:4,1:5", vec![(1, 10)])]
+    #[case("", vec![])]
+    fn between_clauses(#[case] numstr: &str, #[case] clauses: Vec<(i64, i64)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_between_clauses(), clauses);
+    }
+
+    #[rstest]
+    #[case("-5:15", vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 10, 10, 10, 10])]
+    #[case("20:-10:-20", vec![10, 10, 0, 0, 0])]
+    #[case("3,7", vec![3, 7])]
+    fn saturating_clamp_values(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.saturating_clamp(0, 10).collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case(":", vec![-2, -1, 0, 1, 2, 3])]
+    #[case("1:", vec![1, 2, 3])]
+    #[case(":-1", vec![-2, -1])]
+    #[case(":2:,::-1", vec![-2, 0, 2, -2, -1])]
+    #[case("3::, 2", vec![3, 2])]
+    fn inferred_bounds(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_inferred_bounds(-2, 3)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case(":")]
+    #[case("5:")]
+    #[case(":5")]
+    #[should_panic]
+    fn without_inferred_bounds(#[case] numstr: &str) {
+        NumberRangeOptions::<i64>::new().parse(numstr).unwrap();
+    }
+
+    #[rstest]
+    #[case("1-5;8", "1-5;8", "1:5,8")]
+    #[case(" 10 - 7 ; 1 - 2 - 5 ", "10-7;1-2-5", "10:7,1:2:5")]
+    #[case("", "", "")]
+    fn canonical_string(#[case] numstr: &str, #[case] canonical: &str, #[case] display: &str) {
+        let mut rng = NumberRangeOptions::<i64>::new()
+            .with_list_sep(';')
+            .with_range_sep('-')
+            .parse(numstr)
+            .unwrap();
+        rng.options = rng.options.clone().with_list_sep(',').with_range_sep(':');
+        assert_eq!(rng.to_canonical_string(), canonical);
+        assert_eq!(rng.to_string(), display);
+    }
+
+    #[rstest]
+    fn canonical_string_not_parsed() {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_range_sep('-')
+            .parse("1-3")
+            .unwrap();
+        let rng = rng.from_vec([1, 2, 3, 5], None);
+        assert_eq!(rng.to_canonical_string(), "1-3,5");
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn negative_grouped(#[case] validate: bool) {
+        let opts = || {
+            NumberRangeOptions::<i64>::new()
+                .with_group_sep(',')
+                .with_group_validation(validate)
+                .with_list_sep('/')
+        };
+        let rng = opts().parse("-1,000:-500").unwrap();
+        assert_eq!(rng.numbers, vec![Number::Range(-1000, 1, -500)]);
+        assert_eq!(rng.to_grouped_string(), "-1,000:-500");
+        let rng = opts().parse("-1,000:-500:-2,000/ -1,234 /+1,000").unwrap();
+        assert_eq!(
+            rng.collect::<Vec<i64>>(),
+            vec![-1000, -1500, -2000, -1234, 1000]
+        );
+        let rng = opts().parse("-1,500:-1,000:-3,500").unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![-1500, -2500, -3500]);
+        let rng = opts()
+            .with_group_sep('.')
+            .with_decimal_sep(',')
+            .parse("-1.000:-998")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![-1000, -999, -998]);
+    }
+
+    #[rstest]
+    #[case("9,1:3,20:22", 2, vec![1, 2], vec![22, 21])]
+    #[case("9,1:3,20:22", 5, vec![1, 2, 3, 9, 20], vec![22, 21, 20, 9, 3])]
+    #[case("9,1:3,20:22", 10, vec![1, 2, 3, 9, 20, 21, 22], vec![22, 21, 20, 9, 3, 2, 1])]
+    #[case("5:-1:1,3:7,4", 3, vec![1, 2, 3], vec![7, 6, 5])]
+    #[case("10:3:20,-5", 2, vec![-5, 10], vec![19, 16])]
+    #[case("1:1000000000", 0, vec![], vec![])]
+    #[case("0:2:2000000000,1:3:2000000000", 4, vec![0, 1, 2, 4], vec![2000000000, 1999999999, 1999999998, 1999999996])]
+    #[case("3x4,2:4", 3, vec![2, 3, 4], vec![4, 3, 2])]
+    #[case("", 3, vec![], vec![])]
+    fn k_smallest_largest(
+        #[case] numstr: &str,
+        #[case] k: usize,
+        #[case] smallest: Vec<i64>,
+        #[case] largest: Vec<i64>,
+    ) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(NumberRange::k_smallest(&rng, k), smallest);
+        assert_eq!(NumberRange::k_largest(&rng, k), largest);
+    }
+
+    #[rstest]
+    fn k_smallest_largest_bounds() {
+        let rng = NumberRange::<u8>::default().parse_str("0:255").unwrap();
+        assert_eq!(NumberRange::k_smallest(&rng, 2), vec![0, 1]);
+        assert_eq!(NumberRange::k_largest(&rng, 2), vec![255, 254]);
+    }
+
+    #[rstest]
+    #[case(":", Some(1), Some(4), Ok(vec![1, 2, 3, 4]))]
+    #[case("2:", Some(1), Some(4), Ok(vec![2, 3, 4]))]
+    #[case(":2", Some(1), Some(4), Ok(vec![1, 2]))]
+    #[case(":2:", Some(1), Some(4), Ok(vec![1, 3]))]
+    #[case("2:", None, Some(4), Ok(vec![2, 3, 4]))]
+    #[case(":2", Some(1), None, Ok(vec![1, 2]))]
+    #[case(":", None, Some(4), Err(BoundSide::Start))]
+    #[case(":", Some(1), None, Err(BoundSide::End))]
+    #[case(":", None, None, Err(BoundSide::Start))]
+    #[case("2:", Some(1), None, Err(BoundSide::End))]
+    #[case(":2", None, Some(4), Err(BoundSide::Start))]
+    #[case(" :2:3", None, None, Err(BoundSide::Start))]
+    #[case("1:2: ", None, None, Err(BoundSide::End))]
+    fn omitted_bounds(
+        #[case] numstr: &str,
+        #[case] start: Option<i64>,
+        #[case] end: Option<i64>,
+        #[case] expected: Result<Vec<i64>, BoundSide>,
+    ) {
+        let mut opts = NumberRangeOptions::<i64>::new();
+        opts.default_start = start;
+        opts.default_end = end;
+        let parsed = opts
+            .parse(numstr)
+            .map(|rng| rng.collect::<Vec<i64>>())
+            .map_err(|err| match err.downcast_ref::<ParseError>() {
+                Some(ParseError::MissingBound { side }) => *side,
+                _ => panic!("{} isn't MissingBound", err),
+            });
+        assert_eq!(parsed, expected);
+    }
+
+    #[rstest]
+    fn omitted_bounds_interval() {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_math_intervals(true)
+            .with_default_start(0)
+            .parse("[,5]")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![0, 1, 2, 3, 4, 5]);
+        let err = NumberRangeOptions::<i64>::new()
+            .with_math_intervals(true)
+            .parse("[0,)")
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::MissingBound {
+                side: BoundSide::End
+            })
+        );
+        assert_eq!(
+            err.root_cause().to_string(),
+            "Range end is omitted without the default_end option"
+        );
+    }
+
+    #[rstest]
+    fn values_as_f64() {
+        let rng = NumberRange::<i64>::default()
+            .parse_str("-2:2:4,10")
+            .unwrap();
+        assert_eq!(
+            rng.iter_as_f64().collect::<Vec<f64>>(),
+            vec![-2.0, 0.0, 2.0, 4.0, 10.0]
+        );
+        let rng = NumberRange::<u8>::default()
+            .parse_str("255:-100:0")
+            .unwrap();
+        assert_eq!(
+            rng.iter_as_f64().collect::<Vec<f64>>(),
+            vec![255.0, 155.0, 55.0]
+        );
+        let rng = NumberRange::<f32>::default().parse_str("0:0.5:1").unwrap();
+        assert_eq!(rng.iter_as_f64().collect::<Vec<f64>>(), vec![0.0, 0.5, 1.0]);
+        let rng = NumberRange::<u64>::default()
+            .parse_str("9007199254740993")
+            .unwrap();
+        assert_eq!(rng.iter_as_f64().next(), Some(9007199254740992.0));
+    }
+
+    #[rstest]
+    #[case("5:1", vec![1, 2, 3, 4, 5], "1:5")]
+    #[case("1:-2:7", vec![7, 5, 3, 1], "7:-2:1")]
+    #[case("10:3:1", vec![1, 4, 7, 10], "1:3:10")]
+    #[case("1:5,3:3", vec![1, 2, 3, 4, 5, 3], "1:5,3:3")]
+    #[case("9:-3:1", vec![9, 6, 3], "9:-3:1")]
+    fn autofix_direction_signed(
+        #[case] numstr: &str,
+        #[case] expected: Vec<i64>,
+        #[case] display: &str,
+    ) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_autofix_direction(true)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(format!("{}", rng), display);
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("5:1", vec![1, 2, 3, 4, 5])]
+    #[case("1:-1:3", vec![3, 2, 1])]
+    #[case("4:0", vec![0, 1, 2, 3, 4])]
+    fn autofix_direction_unsigned(#[case] numstr: &str, #[case] expected: Vec<u8>) {
+        let rng = NumberRangeOptions::<u8>::new()
+            .with_autofix_direction(true)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<u8>>(), expected);
+    }
+
+    #[rstest]
+    fn autofix_direction_other_options() {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_autofix_direction(true)
+            .with_math_intervals(true)
+            .parse("[3,3),(5,1]")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), Vec::<i64>::new());
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_autofix_direction(true)
+            .with_descending(true)
+            .parse("1:3")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![3, 2, 1]);
+    }
+
+    #[rstest]
+    #[case("1:10,14:2:20", 16, Some(11))]
+    #[case("1:10,14:2:20", 15, None)]
+    #[case("1:10,14:2:20", 22, None)]
+    #[case("5,20:-5:0,3x7,7", 10, Some(3))]
+    #[case("5,20:-5:0,3x7,7", 7, Some(6))]
+    #[case("5,20:-5:0,3x7,7", -5, None)]
+    #[case("5:1,3,1:3", 3, Some(0))]
+    #[case("0x4,4", 4, Some(0))]
+    #[case("10:-3:1", 1, Some(3))]
+    #[case("", 1, None)]
+    fn index_of_value(#[case] numstr: &str, #[case] value: i64, #[case] index: Option<usize>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.index_of(value), index);
+        if let Some(i) = index {
+            assert_eq!(rng.nth_value(i), Some(value));
+        }
+    }
+
+    #[rstest]
+    #[case("-2:+2", vec![8, 9, 10, 11, 12])]
+    #[case("+0,-10", vec![10, 0])]
+    #[case("5:+2", vec![5, 6, 7, 8, 9, 10, 11, 12])]
+    #[case("+5:-1:-2", vec![15, 14, 13, 12, 11, 10, 9, 8])]
+    #[case("-20", vec![-10])]
+    #[case("+-3:+3", vec![7, 8, 9, 10, 11, 12, 13])]
+    fn relative_values(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRange::<i64>::default()
+            .parse_relative(numstr, 10)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("-11")]
+    #[case("+x")]
+    #[case("-")]
+    #[should_panic]
+    fn relative_values_error(#[case] numstr: &str) {
+        NumberRange::<u32>::default()
+            .parse_relative(numstr, 10)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn relative_values_bounds() {
+        let rng = NumberRange::<u8>::default;
+        assert!(rng().parse_relative("+10", 250).is_err());
+        assert!(rng().parse_relative("-5:+5", 250).is_ok());
+        let rng = NumberRange::<i8>::default;
+        assert!(rng().parse_relative("-1", -128).is_err());
+        assert!(rng().parse_relative("+0:+127", -128).is_ok());
+    }
+
+    #[rstest]
+    fn relative_base_reparse() {
+        let mut rng = NumberRange::<i64>::default()
+            .parse_relative("-2:+2", 10)
+            .unwrap();
+        assert_eq!(rng.by_ref().collect::<Vec<i64>>(), vec![8, 9, 10, 11, 12]);
+        let rng = rng.parse().unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![8, 9, 10, 11, 12]);
+        let rng = NumberRange::<i64>::default()
+            .parse_relative("-2", 10)
+            .unwrap();
+        let rng = rng.parse_str("-2").unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![-2]);
+    }
+
+    #[rstest]
+    #[case("1:5,8", true)]
+    #[case("1:5,3", false)]
+    #[case("1:5,5", false)]
+    #[case("1:5,6:2:10,20", true)]
+    #[case("1,5:-1:3", false)]
+    #[case("1,5:-1:5,7", true)]
+    #[case("1,1x5,7", true)]
+    #[case("1,2x5,7", false)]
+    #[case("1:3,10:1,4", true)]
+    #[case("", true)]
+    fn monotonic_increasing(#[case] numstr: &str, #[case] increasing: bool) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.is_monotonic_increasing(), increasing);
+        let values = rng.collect::<Vec<i64>>();
+        assert_eq!(values.windows(2).all(|w| w[0] < w[1]), increasing);
+    }
+
+    #[rstest]
+    #[case("1:auto:9", 5, vec![1, 3, 5, 7, 9])]
+    #[case("0: auto :100", 3, vec![0, 50, 100])]
+    #[case("9:auto:-3", 4, vec![9, 5, 1, -3])]
+    #[case("4:auto:4", 1, vec![4])]
+    #[case("1:auto:2,7", 2, vec![1, 2, 7])]
+    fn target_count(#[case] numstr: &str, #[case] count: usize, #[case] expected: Vec<i64>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_target_count(count)
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1:auto:10", Some(5))]
+    #[case("1:auto:9", Some(1))]
+    #[case("1:auto:1", Some(3))]
+    #[case("1:auto:9", None)]
+    #[case("1:auto", Some(5))]
+    #[should_panic]
+    fn target_count_error(#[case] numstr: &str, #[case] count: Option<usize>) {
+        let mut opts = NumberRangeOptions::<i64>::new();
+        opts.target_count = count;
+        opts.parse(numstr).unwrap();
+    }
+
+    #[rstest]
+    fn target_count_float() {
+        let rng = NumberRangeOptions::<f64>::new()
+            .with_target_count(5)
+            .parse("0:auto:1")
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<f64>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[rstest]
+    fn comma_test_empty_range() {
+        assert_eq!(
+            NumberRange::default()
+                .parse_str("")
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            vec![]
+        );
+        // testing to make sure it removes the old values from iterators
+        let rng = NumberRange::default().parse_str("1:10").unwrap();
+        assert_eq!(rng.parse_str("").unwrap().collect::<Vec<f64>>(), vec![]);
+    }
+
+    #[rstest]
+    #[case("9,1:3,5", vec![1, 2, 3, 5, 9])]
+    #[case("10:-3:1,2:2:8", vec![1, 2, 4, 4, 6, 7, 8, 10])]
+    #[case("5,5,1", vec![1, 5, 5])]
+    #[case("4:1,3", vec![3])]
+    #[case("", vec![])]
+    fn sorted_iter(#[case] numstr: &str, #[case] numvec: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.iter_sorted().collect::<Vec<i64>>(), numvec);
+    }
+
+    #[rstest]
+    fn sorted_iter_usize() {
+        let rng = NumberRange::<usize>::default()
+            .parse_str("20:-5:0,3:7")
+            .unwrap();
+        assert_eq!(
+            rng.iter_sorted().collect::<Vec<usize>>(),
+            vec![0, 3, 4, 5, 5, 6, 7, 10, 15, 20]
+        );
+    }
+
+    #[rstest]
+    #[case("1:3", "10:13", vec![1, 10, 2, 11, 3, 12, 13])]
+    #[case("10:13", "1:3", vec![10, 1, 11, 2, 12, 3, 13])]
+    #[case("1,5", "", vec![1, 5])]
+    #[case("", "", vec![])]
+    fn interleave_ranges(#[case] numstr1: &str, #[case] numstr2: &str, #[case] numvec: Vec<i64>) {
+        let rng1 = NumberRange::<i64>::default().parse_str(numstr1).unwrap();
+        let rng2 = NumberRange::<i64>::default().parse_str(numstr2).unwrap();
+        assert_eq!(rng1.interleave(rng2).collect::<Vec<i64>>(), numvec);
+    }
+
+    #[rstest]
+    #[case("1:5", "1:1:5")]
+    #[case("3", "3")]
+    #[case("1:5,7,-1:-2:-9", "1:1:5,7,-1:-2:-9")]
+    #[case("", "")]
+    fn explicit_string(#[case] numstr: &str, #[case] explicit: &str) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_string_explicit(), explicit);
+    }
+
+    #[rstest]
+    #[case("1:5", Some((1, 1, 5, true)))]
+    #[case("10:-2:1", Some((10, -2, 1, false)))]
+    #[case("5", None)]
+    #[case("1:5,7", None)]
+    #[case("", None)]
+    fn single_range(#[case] numstr: &str, #[case] info: Option<(i64, i64, i64, bool)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.single_range_info(), info);
+    }
+
+    #[rstest]
+    fn single_range_usize() {
+        let rng = NumberRange::<usize>::default()
+            .parse_str("10:-2:1")
+            .unwrap();
+        assert_eq!(rng.single_range_info(), Some((10, 2, 1, false)));
+    }
+
+    #[rstest]
+    #[case("1:3,8", vec![(1, false), (2, false), (3, false), (8, true)])]
+    #[case("5", vec![(5, true)])]
+    #[case("1,4:2", vec![(1, true)])]
+    #[case("", vec![])]
+    fn with_last(#[case] numstr: &str, #[case] numvec: Vec<(i64, bool)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.iter_with_last().collect::<Vec<(i64, bool)>>(), numvec);
+    }
+
+    #[rstest]
+    fn filter_even() {
+        let rng = NumberRange::<i64>::default().parse_str("1:10").unwrap();
+        assert_eq!(
+            rng.iter().filter(|v| v % 2 == 0).collect::<Vec<i64>>(),
+            vec![2, 4, 6, 8, 10]
+        );
+        assert_eq!(
+            rng.filter_values(|v| v % 2 == 0).collect::<Vec<i64>>(),
+            vec![2, 4, 6, 8, 10]
+        );
+    }
+
+    #[rstest]
+    fn iter_borrowed() {
+        let mut rng = NumberRange::<usize>::default()
+            .parse_str("1,10:-3:1,4:2")
+            .unwrap();
+        assert_eq!(rng.iter().collect::<Vec<usize>>(), vec![1, 10, 7, 4, 1]);
+        rng.next();
+        assert_eq!(rng.iter().collect::<Vec<usize>>(), vec![10, 7, 4, 1]);
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![10, 7, 4, 1]);
+    }
+
+    #[rstest]
+    #[case("0,1,3", 8, "0b00001011")]
+    #[case("0:7", 8, "0b11111111")]
+    #[case("-2,2,9", 4, "0b0100")]
+    #[case("1:2:7", 8, "0b10101010")]
+    #[case("", 3, "0b000")]
+    #[case("1", 0, "0b")]
+    #[case("0:4000000000", 8, "0b11111111")]
+    #[case("-4000000000:2:4000000000", 6, "0b010101")]
+    #[case("9223372036854775807:-2:3", 6, "0b101000")]
+    fn mask_string(#[case] numstr: &str, #[case] width: usize, #[case] mask: &str) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_mask_string(width), mask);
+    }
+
+    #[rstest]
+    fn mask_string_narrow_type() {
+        let rng = NumberRange::<u8>::default().parse_str("0:255").unwrap();
+        assert_eq!(rng.to_mask_string(300).matches('1').count(), 256);
+        assert!(rng
+            .to_mask_string(300)
+            .starts_with(&format!("0b{}1", "0".repeat(44))));
+    }
+
+    #[rstest]
+    #[case("1,3:4", 0, 5, vec![false, true, false, true, true, false])]
+    #[case("10:-3:1,-4", -5, -2, vec![false, true, false, false])]
+    #[case("1:10", 3, 3, vec![true])]
+    #[case("1:10", 5, 3, vec![])]
+    #[case("", 0, 2, vec![false, false, false])]
+    fn bool_mask(#[case] numstr: &str, #[case] lo: i64, #[case] hi: i64, #[case] mask: Vec<bool>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_bool_mask(lo, hi), mask);
+    }
+
+    #[rstest]
+    fn bool_mask_type_bounds() {
+        let rng = NumberRange::<u8>::default().parse_str("0,255").unwrap();
+        let mask = rng.to_bool_mask(0, 255);
+        assert_eq!(mask.len(), 256);
+        assert_eq!(mask.iter().filter(|b| **b).count(), 2);
+        assert!(mask[0] && mask[255]);
+        let rng = NumberRange::<i8>::default()
+            .parse_str("-128,0:2:6,127")
+            .unwrap();
+        let mask = rng.to_bool_mask(-128, 127);
+        assert_eq!(mask.len(), 256);
+        assert_eq!(mask.iter().filter(|b| **b).count(), 6);
+        assert!(mask[0] && mask[128] && mask[134] && mask[255]);
+    }
+
+    #[rstest]
+    fn bool_mask_large_range() {
+        let rng = NumberRange::<i64>::default()
+            .parse_str("-9223372036854775808:9223372036854775807")
+            .unwrap();
+        assert_eq!(rng.to_bool_mask(-2, 1), vec![true; 4]);
+        let rng = NumberRange::<u64>::default()
+            .parse_str("0:3:18446744073709551615")
+            .unwrap();
+        assert_eq!(
+            rng.to_bool_mask(10, 15),
+            vec![false, false, true, false, false, true]
+        );
+    }
+
+    #[rstest]
+    fn stepped_by_function() {
+        let rng = NumberRange::<i64>::default().parse_str("1:100").unwrap();
+        assert_eq!(
+            rng.iter_stepped_by(|x| x * 2 + 1).collect::<Vec<i64>>(),
+            vec![1, 3, 7, 15, 31, 63]
+        );
+        let rng = NumberRange::<i64>::default().parse_str("100:-1:1").unwrap();
+        assert_eq!(
+            rng.iter_stepped_by(|x| x / 3).collect::<Vec<i64>>(),
+            vec![100, 33, 11, 3, 1]
+        );
+        let rng = NumberRange::<i64>::default().parse_str("1:10,12").unwrap();
+        assert_eq!(rng.iter_stepped_by(|x| x + 1).count(), 0);
+    }
+
+    #[rstest]
+    #[case("1,3:10,20:-2:14")]
+    #[case("1:2:9,5")]
+    #[case("")]
+    fn config_roundtrip(#[case] numstr: &str) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        let config = rng.to_config();
+        let options = NumberRangeOptions::<i64>::new().with_repeat_marker('x');
+        let rng2 = NumberRange::from_options(options)
+            .from_config(config.clone())
+            .unwrap();
+        assert_eq!(rng2.to_config(), config);
+        assert_eq!(format!("{}", rng2), numstr);
+        assert!(rng.take(20).eq(rng2.take(20)));
+    }
+
+    #[rstest]
+    #[case("20:2:14,1:3", vec![(1, None, 3)], vec![1, 2, 3])]
+    #[case("3:-1:5,14:-2:20", vec![], vec![])]
+    #[case("20:-2:14,5:3", vec![(20, Some(-2), 14)], vec![20, 18, 16, 14])]
+    fn config_roundtrip_empty(
+        #[case] numstr: &str,
+        #[case] expected: Vec<(i64, Option<i64>, i64)>,
+        #[case] values: Vec<i64>,
+    ) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        let config = rng.to_config();
+        assert_eq!(config, expected);
+        let rng2 = NumberRange::<i64>::default().from_config(config).unwrap();
+        assert_eq!(rng2.collect::<Vec<i64>>(), values);
+        assert_eq!(rng.collect::<Vec<i64>>(), values);
+    }
+
+    #[rstest]
+    fn config_repeat_expanded() {
+        let rng = NumberRangeOptions::<u8>::new()
+            .with_repeat_marker('x')
+            .parse("3x5,2x7,1:2")
+            .unwrap();
+        let config = rng.to_config();
+        assert_eq!(
+            config,
+            vec![
+                (5, None, 5),
+                (5, None, 5),
+                (5, None, 5),
+                (7, None, 7),
+                (7, None, 7),
+                (1, None, 2)
+            ]
+        );
+        let rng = NumberRange::<u8>::default().from_config(config).unwrap();
+        assert_eq!(format!("{}", rng), "5,5,5,7,7,1:2");
+        assert_eq!(rng.collect::<Vec<u8>>(), vec![5, 5, 5, 7, 7, 1, 2]);
+    }
+
+    #[rstest]
+    fn config_roundtrip_usize_empty() {
+        let rng = NumberRange::<usize>::default()
+            .parse_str("20:2:14,20:-2:14,1:-1:3")
+            .unwrap();
+        let config = rng.to_config();
+        assert_eq!(config, vec![(20, Some(2), 14)]);
+        let rng = NumberRange::<usize>::default().from_config(config).unwrap();
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![20, 18, 16, 14]);
+    }
+
+    #[rstest]
+    fn config_roundtrip_usize() {
+        let rng = NumberRange::<usize>::default()
+            .parse_str("10:-3:1,4,5:7")
+            .unwrap();
+        let config = rng.to_config();
+        assert_eq!(config, vec![(10, Some(3), 1), (4, None, 4), (5, None, 7)]);
+        let rng = NumberRange::<usize>::default().from_config(config).unwrap();
+        assert_eq!(format!("{}", rng), "10:-3:1,4,5:7");
+        assert_eq!(rng.collect::<Vec<usize>>(), vec![10, 7, 4, 1, 4, 5, 6, 7]);
+    }
+
+    #[rstest]
+    #[case(vec![(1, Some(0), 5)], "Step can't be zero")]
+    #[case(vec![(1, None, 3), (1, Some(-1), 5)], "Config entry 1 Range has no values")]
+    #[case(vec![(5, None, 1)], "Range has no values")]
+    #[case(vec![(5, Some(-2), 9)], "Range has no values")]
+    fn config_invalid(#[case] config: Vec<(i64, Option<i64>, i64)>, #[case] reason: &str) {
+        let err = NumberRange::<i64>::default()
+            .from_config(config)
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains(reason), "{:#}", err);
+    }
+
+    #[rstest]
+    fn config_invalid_float() {
+        let rng = NumberRange::<f64>::default().from_config([(1.0, Some(f64::NAN), 5.0)]);
+        assert!(rng.is_err());
+        let rng = NumberRange::<f64>::default().from_config([(1.0, None, f64::INFINITY)]);
+        assert!(rng.is_err());
+    }
+
+    #[rstest]
+    fn config_checked_like_parse() {
+        let options = NumberRangeOptions::<i64>::new()
+            .with_autofix_direction(true)
+            .with_max_segment_count(3);
+        let rng = NumberRange::from_options(options.clone())
+            .from_config([(5, None, 3), (9, Some(-2), 7)])
+            .unwrap();
+        assert_eq!(rng.collect::<Vec<i64>>(), vec![3, 4, 5, 9, 7]);
+        let rng = NumberRange::from_options(options).from_config([(1, None, 4)]);
+        assert!(rng.is_err());
+    }
+
+    #[rstest]
+    #[case("1:0.5:3", vec![1, 2, 2, 3, 3])]
+    #[case("-1.5,-0.4,0.4", vec![-2, 0, 0])]
+    #[case("2.5:-1:-0.5", vec![3, 2, 1, -1])]
+    fn rounded_collect(#[case] numstr: &str, #[case] numvec: Vec<i64>) {
+        let rng = NumberRange::<f64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.collect_rounded::<i64>(), numvec);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[rstest]
+    fn rounded_collect_out_of_range() {
+        let rng = NumberRange::<f64>::default()
+            .parse_str("-1,1.2,300")
+            .unwrap();
+        assert_eq!(rng.collect_rounded::<u8>(), vec![1]);
+    }
 
     #[rstest]
-    fn manual_build() {
-        let mut rng = NumberRange::<i64>::default();
-        rng.numbers.push_back(Number::Single(1));
-        rng.numbers.push_back(Number::Range(3, 2, 6));
-        rng.numbers.push_back(Number::Range(-4, 1, -2));
-        assert_eq!(format!("{}", rng), "1,3:2:6,-4:-2");
-        assert_eq!(rng.collect::<Vec<i64>>(), vec![1, 3, 5, -4, -3, -2]);
+    #[case("1:4", vec![1, 3, 6, 10])]
+    #[case("5,-2,3:-1:1", vec![5, 3, 6, 8, 9])]
+    #[case("", vec![])]
+    fn cumulative_sum(#[case] numstr: &str, #[case] numvec: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.cumulative().collect::<Vec<i64>>(), numvec);
     }
 
     #[rstest]
-    fn options_build() {
-        let rng: NumberRange<usize> = NumberRangeOptions::<usize>::default()
-            .with_list_sep('*')
-            .with_range_sep('/')
-            .parse("1*3/5*9/2/15")
-            .expect("Parsing should be succesful");
-        assert_eq!(rng.collect::<Vec<usize>>(), vec![1, 3, 4, 5, 9, 11, 13, 15]);
+    #[case("10:-1:1", "1:10")]
+    #[case("10:-3:0,5", "1:3:10,5")]
+    #[case("7,10:-4:3,1:2", "1:2,6:4:10,7")]
+    #[case("1:3:8,4:1", "1:3:7")]
+    fn ascending(#[case] numstr: &str, #[case] ascstr: &str) {
+        let mut rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        let mut values: Vec<i64> = rng.iter().collect();
+        rng.to_ascending();
+        assert_eq!(format!("{}", rng), ascstr);
+        let mut asc_values: Vec<i64> = rng.collect();
+        values.sort();
+        asc_values.sort();
+        assert_eq!(values, asc_values);
     }
 
     #[rstest]
-    fn manual_build_then_modify() {
-        let mut rng = NumberRange::<i64>::default();
-        rng.numbers.push_back(Number::Single(1));
-        rng.numbers.push_back(Number::Range(3, 2, 6));
-        rng.numbers.push_back(Number::Range(-4, 1, -2));
-        let values = vec![1, 3, 5, -4, -3, -2];
-        assert_eq!(format!("{}", rng), "1,3:2:6,-4:-2");
-        for i in 0..4 {
-            assert_eq!(rng.next().expect("Should have next"), values[i]);
-        }
-        assert_eq!(format!("{}", rng), "-3:-2");
-        rng.numbers.push_back(Number::Single(1));
-        assert_eq!(format!("{}", rng), "-3:-2,1");
-        assert_eq!(rng.collect::<Vec<i64>>(), vec![-3, -2, 1]);
+    fn ascending_usize() {
+        let mut rng = NumberRange::<usize>::default()
+            .parse_str("10:-3:0,2")
+            .unwrap();
+        rng.to_ascending();
+        assert_eq!(format!("{}", rng), "1:3:10,2");
     }
 
     #[rstest]
-    fn options_build_then_modify() {
-        let mut rng: NumberRange<usize> = NumberRangeOptions::<usize>::default()
-            .with_list_sep(':')
-            .with_range_sep('-')
-            .parse("1:3-5:9")
-            .expect("Parsing should be succesful");
-        let values = vec![1, 3, 4, 5, 9];
-        for i in 0..4 {
-            assert_eq!(rng.next().expect("Should have next"), values[i]);
+    #[case("0:10", 0.5, Some(5))]
+    #[case("0:10", 0.0, Some(0))]
+    #[case("0:10", 1.0, Some(10))]
+    #[case("0:10", 0.34, Some(3))]
+    #[case("1,5,9:-2:3", 0.5, Some(7))]
+    #[case("1,5,9:-2:3", 0.9, Some(3))]
+    #[case("7", 0.7, Some(7))]
+    #[case("0:10", -0.1, None)]
+    #[case("0:10", f64::NAN, None)]
+    #[case("", 0.5, None)]
+    fn fraction_value(#[case] numstr: &str, #[case] t: f64, #[case] value: Option<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.value_at_fraction(t), value);
+    }
+
+    #[rstest]
+    fn nth_values() {
+        let rng = NumberRange::<usize>::default()
+            .parse_str("3,10:-3:0,5:7,2:1")
+            .unwrap();
+        let values: Vec<usize> = rng.iter().collect();
+        assert_eq!(rng.total_count(), values.len());
+        for (i, v) in values.iter().enumerate() {
+            assert_eq!(rng.nth_value(i), Some(*v));
         }
-        assert_eq!(format!("{}", rng), "9");
-        rng.numbers.push_back(Number::Range(11, 2, 15));
-        assert_eq!(format!("{}", rng), "9:11-2-15");
-        assert_eq!(rng.collect::<Vec<usize>>(), vec![9, 11, 13, 15]);
+        assert_eq!(rng.nth_value(values.len()), None);
     }
 
     #[rstest]
-    #[case("200", vec![200])]
-    #[case("-200", vec![-200])]
-    #[case("1,4", vec![1, 4])]
-    #[case("1, -4", vec![1, -4])]
-    #[should_panic]
-    #[case("1.0, 2", vec![])]
-    #[case("1: 3", vec![1, 2, 3])]
-    #[case(" -1:10", vec![-1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10])]
-    #[case("3 , 5:10", vec![3, 5, 6, 7, 8, 9, 10])]
-    fn comma_default_int(#[case] numstr: &str, #[case] numvec: Vec<i64>) {
-        assert_eq!(
-            NumberRange::<i64>::default()
-                .parse_str(numstr)
-                .unwrap()
-                .collect::<Vec<i64>>(),
-            numvec
-        );
+    #[case("2:5,22", "1:10,20:30", true)]
+    #[case("1:10,20:30", "1:10,20:30", true)]
+    #[case("1:10", "1:5,6:10", true)]
+    #[case("1:2:9", "1,3,5,7,9,11", true)]
+    #[case("10:-1:1", "1:10", true)]
+    #[case("", "1:10", true)]
+    #[case("", "", true)]
+    #[case("1:10,20:30", "2:5,22", false)]
+    #[case("1:11", "1:10", false)]
+    #[case("1:10", "1:2:10", false)]
+    #[case("5", "", false)]
+    #[case("0:6:2000000000", "0:2:2000000000,0:3:2000000000", true)]
+    #[case("0:2:2000000000", "0:4:2000000000,2:4:2000000000", true)]
+    #[case("0:2:2000000000", "0:4:2000000000,2:4:1999999990", false)]
+    #[case("1:2:19", "0:9,11:2:21", true)]
+    fn subset(#[case] numstr1: &str, #[case] numstr2: &str, #[case] expected: bool) {
+        let rng1 = NumberRange::<i64>::default().parse_str(numstr1).unwrap();
+        let rng2 = NumberRange::<i64>::default().parse_str(numstr2).unwrap();
+        assert_eq!(rng1.is_subset_of(&rng2), expected);
     }
 
     #[rstest]
-    fn limits_test() {
-        let nr1: Vec<usize> = NumberRangeOptions::<usize>::new()
-            .with_range_sep('-')
-            .with_default_start(0)
-            .parse("-2")
-            .unwrap()
-            .collect();
-        assert_eq!(nr1, vec![0, 1, 2]);
-        let nr1: Vec<usize> = NumberRangeOptions::<usize>::new()
-            .with_range_sep('-')
-            .with_default_end(5)
-            .parse("2-")
-            .unwrap()
-            .collect();
-        assert_eq!(nr1, vec![2, 3, 4, 5]);
-        let nr1: Vec<usize> = NumberRangeOptions::<usize>::new()
-            .with_range_sep('-')
-            .with_default_start(0)
-            .with_default_end(5)
-            .parse("-")
+    fn subset_entangled() {
+        let rng = NumberRange::<i64>::default()
+            .parse_str("0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:50000000")
+            .unwrap();
+        assert!(rng.is_subset_of(&rng));
+        let fewer = NumberRange::<i64>::default()
+            .parse_str("0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:40000000")
+            .unwrap();
+        assert!(fewer.is_subset_of(&rng));
+        assert!(!rng.is_subset_of(&fewer));
+    }
+
+    #[rstest]
+    fn cached_iter() {
+        let cached = NumberRange::<usize>::default()
+            .parse_str("1:3,10:-4:1")
             .unwrap()
-            .collect();
-        assert_eq!(nr1, vec![0, 1, 2, 3, 4, 5]);
+            .into_cached();
+        for _ in 0..3 {
+            assert_eq!(
+                cached.iter().collect::<Vec<usize>>(),
+                vec![1, 2, 3, 10, 6, 2]
+            );
+        }
+        assert_eq!(cached.as_slice(), &[1, 2, 3, 10, 6, 2]);
     }
 
     #[rstest]
-    #[case("200", vec![200])]
-    #[case("1,4", vec![1, 4])]
-    #[should_panic]
-    #[case("1,-4", vec![])]
-    #[should_panic]
-    #[case(",4", vec![])]
-    #[should_panic]
-    #[case("1,,4", vec![])]
-    #[should_panic]
-    #[case("1,4,", vec![])]
-    #[should_panic]
-    #[case("1,4.0", vec![])]
-    #[case("1:3", vec![1, 2, 3])]
-    #[case("1:10", vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10])]
-    fn default_usize(#[case] numstr: &str, #[case] numvec: Vec<usize>) {
-        assert_eq!(
-            NumberRange::<usize>::default()
-                .parse_str(numstr)
-                .unwrap()
-                .collect::<Vec<usize>>(),
-            numvec
-        );
+    #[case("1:5", vec![2, 3, 4])]
+    #[case("1:3,8,10:-2:6", vec![2, 3, 8, 10, 8])]
+    #[case("1,2", vec![])]
+    #[case("1", vec![])]
+    #[case("", vec![])]
+    fn interior_values(#[case] numstr: &str, #[case] numvec: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.interior().collect::<Vec<i64>>(), numvec);
     }
 
     #[rstest]
-    #[case("200", vec!["200"])]
-    #[case("1,4", vec!["1,4", "4"])]
-    #[case("1:4", vec!["1:4", "2:4", "3:4", "4:4"])]
-    #[case("10:-4:4", vec!["10:-4:4", "6:-4:4"])]
-    #[case("4:-1:1", vec!["4:-1:1", "3:-1:1", "2:-1:1", "1:-1:1"])]
-    #[case("1:4:10", vec!["1:4:10", "5:4:10", "9:4:10"])]
-    fn format_test_loop(#[case] numstr: &str, #[case] numvec: Vec<&str>) {
-        let mut rng: NumberRange<i64> = NumberRange::default().parse_str(numstr).unwrap();
-        for fmt_str in numvec {
-            assert_eq!(fmt_str, format!("{}", &rng));
-            rng.next();
+    #[case("1200", 3, "1,200")]
+    #[case("-1200:-2:-1204", 3, "-1,200:-2:-1,204")]
+    #[case("1_000_000/999/1000", 3, "1,000,000/999/1,000")]
+    #[case("12345678", 4, "1234,5678")]
+    #[case("123456", 2, "12,34,56")]
+    #[case("123456", 0, "123456")]
+    fn grouped_string(#[case] numstr: &str, #[case] size: usize, #[case] grouped: &str) {
+        let mut rng = NumberRangeOptions::<i64>::new()
+            .with_list_sep('/')
+            .with_group_size(size)
+            .parse(numstr)
+            .unwrap();
+        rng.options.group_sep = ',';
+        assert_eq!(rng.to_grouped_string(), grouped);
+    }
+
+    #[rstest]
+    fn grouped_string_float() {
+        let rng = NumberRange::<f64>::default()
+            .parse_str("1234.5678")
+            .unwrap();
+        assert_eq!(rng.to_grouped_string(), "1_234.5678");
+    }
+
+    #[rstest]
+    #[case("1:10", "5:15", true)]
+    #[case("1:10,20:30", "15,25", true)]
+    #[case("10:-1:1", "10", true)]
+    #[case("1:10", "11:20", false)]
+    #[case("1:5", "6", false)]
+    #[case("1:10,20:30", "11:19,31", false)]
+    #[case("1:2:9", "2:2:10", false)]
+    #[case("", "1:10", false)]
+    #[case("0:2:2000000000", "1:2:2000000001", false)]
+    #[case("0:4:2000000000", "2:6:2000000000", true)]
+    #[case("0:4:2000000000", "2:8:2000000000,1999999999", false)]
+    fn overlapping(#[case] numstr1: &str, #[case] numstr2: &str, #[case] expected: bool) {
+        let rng1 = NumberRange::<i64>::default().parse_str(numstr1).unwrap();
+        let rng2 = NumberRange::<i64>::default().parse_str(numstr2).unwrap();
+        assert_eq!(rng1.overlaps(&rng2), expected);
+        assert_eq!(rng2.overlaps(&rng1), expected);
+    }
+
+    #[rstest]
+    #[case("1:10", "5:15", 6)]
+    #[case("1:10,20:30", "8:22", 6)]
+    #[case("1:10,5:15", "10:-1:1", 10)]
+    #[case("1:10", "11:20", 0)]
+    #[case("1:20", "3,5:7,30", 4)]
+    #[case("0:3:30", "0:5:30", 3)]
+    #[case("1:2:9", "2:2:10", 0)]
+    #[case("0:2:12", "0:3:12", 3)]
+    #[case("0:2:10", "1:10", 5)]
+    #[case("0:4:40,2:4:40", "0:3:40", 7)]
+    #[case("0:2:12,0:3:12", "0:6:12", 3)]
+    #[case("1:2:1000000001", "0:3:1000000000", 166666667)]
+    #[case("", "1:10", 0)]
+    fn intersection_count(#[case] numstr1: &str, #[case] numstr2: &str, #[case] expected: usize) {
+        let rng1 = NumberRange::<i64>::default().parse_str(numstr1).unwrap();
+        let rng2 = NumberRange::<i64>::default().parse_str(numstr2).unwrap();
+        assert_eq!(rng1.intersection_count(&rng2), expected);
+        assert_eq!(rng2.intersection_count(&rng1), expected);
+    }
+
+    #[rstest]
+    fn intersection_count_entangled() {
+        let rng = NumberRange::<i64>::default()
+            .parse_str("0:3:100000000,1:7:90000000,2:11:80000000,5:13:70000000,4:17:60000000,9:19:50000000")
+            .unwrap();
+        assert_eq!(rng.intersection_count(&rng), 51737904);
+    }
+
+    #[rstest]
+    fn intersection_count_bounds() {
+        let rng = NumberRange::<u8>::default().parse_str("0:255").unwrap();
+        assert_eq!(rng.intersection_count(&rng), 256);
+        let rng = NumberRange::<i64>::default()
+            .parse_str("-9223372036854775808:9223372036854775807")
+            .unwrap();
+        assert_eq!(rng.intersection_count(&rng), usize::MAX);
+        let other = NumberRange::<i64>::default()
+            .parse_str("-9223372036854775808:1:-9223372036854775805")
+            .unwrap();
+        assert_eq!(rng.intersection_count(&other), 4);
+        let rng = NumberRange::<u128>::default()
+            .parse_str("0:340282366920938463463374607431768211455")
+            .unwrap();
+        assert_eq!(rng.intersection_count(&rng), usize::MAX);
+    }
+
+    #[rstest]
+    #[case("1,4,6:8", vec![1, 4, 6, 7, 8])]
+    #[case("8:-2:2,5", vec![2, 4, 5, 6, 8])]
+    #[case("-4:5:2000000000,3", vec![-4, 1, 3, 6, 11])]
+    #[case("", vec![])]
+    fn predicate(#[case] numstr: &str, #[case] expected: Vec<i32>) {
+        let rng = NumberRange::<i32>::default().parse_str(numstr).unwrap();
+        let data: Vec<i32> = (-5..15).collect();
+        let filtered: Vec<i32> = data.into_iter().filter(rng.into_predicate()).collect();
+        assert_eq!(filtered, expected);
+    }
+
+    #[rstest]
+    #[case(4, "1,3,…,197,199")]
+    #[case(5, "1,3,5,…,197,199")]
+    #[case(1, "1,…")]
+    #[case(0, "…")]
+    #[case(100, &(1..200).step_by(2).join(","))]
+    fn abbreviated_string(#[case] max: usize, #[case] expected: &str) {
+        let mut rng = NumberRange::<i64>::default();
+        for v in (1..200).step_by(2) {
+            rng.numbers.push_back(Number::Single(v));
         }
-        assert!(rng.next().is_none());
+        assert_eq!(rng.to_abbreviated_string(max), expected);
     }
 
     #[rstest]
-    #[case("200", ',',vec![200])]
-    #[case("1,4", ',', vec![1, 4])]
-    #[case("1:4", ':', vec![1, 4])]
-    #[case("1:4:4", ':', vec![1, 4, 4])]
-    #[case("1/4", '/', vec![1, 4])]
-    #[should_panic]
-    #[case("1--4", '-', vec![])]
-    #[should_panic]
-    #[case("1,-4", ':', vec![])]
-    fn comma_test_sep_usize(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<usize>) {
-        assert_eq!(
-            NumberRangeOptions::<usize>::new()
-                .with_list_sep(sep)
-                .parse(numstr)
-                .unwrap()
-                .collect::<Vec<usize>>(),
-            numvec
-        );
+    #[case("1:10", "5:15", "1:4,11:15")]
+    #[case("1:10", "3,5:6", "1:2,4,7:10")]
+    #[case("1:5", "6:10", "1:10")]
+    #[case("1:5,20", "1:5", "20")]
+    #[case("1:2:9", "1:9", "2:2:8")]
+    #[case("0:2:4000000000", "1:10", "0,1:2:9,12:2:4000000000")]
+    #[case("0:2:20", "0:3:20", "2:6:20,3:6:15,4:6:16")]
+    #[case("1:5", "5:-1:1", "")]
+    #[case("", "3", "3")]
+    fn symmetric_difference(#[case] numstr1: &str, #[case] numstr2: &str, #[case] expected: &str) {
+        let rng1 = NumberRange::<i64>::default().parse_str(numstr1).unwrap();
+        let rng2 = NumberRange::<i64>::default().parse_str(numstr2).unwrap();
+        assert_eq!(format!("{}", rng1.symmetric_difference(&rng2)), expected);
+        assert_eq!(format!("{}", rng2.symmetric_difference(&rng1)), expected);
     }
 
     #[rstest]
-    #[case("200", '-',vec![200])]
-    #[case("1-4", '-', vec![1,2,3,4])]
-    #[case("1:3:4", ':', vec![1, 4])]
-    #[should_panic]
-    #[case("4:-3:1", ':', vec![])]
-    #[should_panic]
-    #[case("1--4", '-', vec![])]
-    fn comma_test_range_usize(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<usize>) {
+    fn symmetric_difference_bounds() {
+        let rng1 = NumberRange::<u8>::default().parse_str("0:255").unwrap();
+        let rng2 = NumberRange::<u8>::default().parse_str("0,100,255").unwrap();
         assert_eq!(
-            NumberRangeOptions::<usize>::new()
-                .with_range_sep(sep)
-                .parse(numstr)
-                .unwrap()
-                .collect::<Vec<usize>>(),
-            numvec
+            format!("{}", rng1.symmetric_difference(&rng2)),
+            "1:99,101:254"
         );
     }
 
     #[rstest]
-    #[case("200", '-',vec![200])]
-    #[case("1-4", '-', vec![1,2,3,4])]
-    #[case("1:3:4", ':', vec![1, 4])]
-    #[case("4:-3:1", ':', vec![4, 1])]
-    #[case("-4:1", ':', vec![-4, -3, -2, -1, 0, 1])]
-    #[case("1:-4", ':', vec![])]
-    fn comma_test_range_i64(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<i64>) {
-        assert_eq!(
-            NumberRangeOptions::<i64>::new()
-                .with_range_sep(sep)
-                .parse(numstr)
-                .unwrap()
-                .collect::<Vec<i64>>(),
-            numvec
-        );
+    #[case("1:5,8,10:2:20", vec![1, 5, 8, 10, 20])]
+    #[case("1:2:10,5:5", vec![1, 9, 5])]
+    #[case("10:-3:1,4:1", vec![10, 1])]
+    #[case("", vec![])]
+    fn boundaries(#[case] numstr: &str, #[case] expected: Vec<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.boundaries(), expected);
     }
 
     #[rstest]
-    #[case("200", '-',vec![200.0])]
-    #[case("1-4", '-', vec![1.0,2.0,3.0,4.0])]
-    #[case("1:3:4", ':', vec![1.0, 4.0])]
-    #[case("1:.5:4", ':', vec![1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0])]
-    #[case("4:-3:1", ':', vec![4.0, 1.0])]
-    #[case("-4:1", ':', vec![-4.0, -3.0, -2.0, -1.0, 0.0, 1.0])]
-    #[case("1:-4", ':', vec![])]
-    fn comma_test_range_f64(#[case] numstr: &str, #[case] sep: char, #[case] numvec: Vec<f64>) {
+    #[case("1:20", 5, 15, "5:15")]
+    #[case("1:3:20,3", 5, 15, "7:3:13")]
+    #[case("20:-4:0", 5, 15, "12:-4:8")]
+    #[case("1:4,16:20,10", 5, 15, "10")]
+    #[case("5:5,6:2:6", 5, 15, "5:5,6:2:6")]
+    #[case("-10:10", -3, 2, "-3:2")]
+    fn clamp_to_range(
+        #[case] numstr: &str,
+        #[case] low: i64,
+        #[case] high: i64,
+        #[case] expected: &str,
+    ) {
+        let mut rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        rng.clamp_to_range(low..=high);
+        assert_eq!(format!("{}", rng), expected);
+    }
+
+    #[rstest]
+    fn clamp_to_range_unsigned() {
+        let mut rng = NumberRange::<u8>::default()
+            .parse_str("250:-7:0,0:255")
+            .unwrap();
+        rng.clamp_to_range(5..=15);
+        assert_eq!(format!("{}", rng), "12:-7:5,5:15");
         assert_eq!(
-            NumberRangeOptions::<f64>::new()
-                .with_range_sep(sep)
-                .parse(numstr)
-                .unwrap()
-                .collect::<Vec<f64>>(),
-            numvec
+            rng.collect::<Vec<u8>>(),
+            vec![12, 5, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
         );
     }
 
     #[rstest]
-    fn comma_test_empty_range() {
+    #[case("1:5;8", "1-5,8")]
+    #[case("1:5;8;20:-2:10", "1-5,8,20:-2:10")]
+    #[case("3:3:12", "3:3:12")]
+    #[case("", "")]
+    fn git_style(#[case] numstr: &str, #[case] expected: &str) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_list_sep(';')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.to_git_style(), expected);
+    }
+
+    #[rstest]
+    #[case("2,4,6,8", Some(2))]
+    #[case("1:5,6:8", Some(1))]
+    #[case("10:-3:1,-2", Some(-3))]
+    #[case("1,2,4", None)]
+    #[case("1:3,2:4", None)]
+    #[case("5,5", None)]
+    #[case("5", None)]
+    #[case("", None)]
+    fn common_step(#[case] numstr: &str, #[case] expected: Option<i64>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.common_step(), expected);
+    }
+
+    #[rstest]
+    fn common_step_unsigned() {
+        let rng = NumberRange::<u32>::default()
+            .parse_str("1,4,7:3:13")
+            .unwrap();
+        assert_eq!(rng.common_step(), Some(3));
+        let rng = NumberRange::<u32>::default().parse_str("13:-3:1").unwrap();
+        assert_eq!(rng.common_step(), None);
+    }
+
+    #[rstest]
+    #[case("1:5,8,10:12", vec![(1, 5), (8, 1), (10, 3)])]
+    #[case("12:-1:10,1:3,4:5,8,8", vec![(1, 5), (8, 1), (10, 3)])]
+    #[case("1:2:7", vec![(1, 1), (3, 1), (5, 1), (7, 1)])]
+    #[case("", vec![])]
+    fn rle(#[case] numstr: &str, #[case] expected: Vec<(i64, usize)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_rle(), expected);
+    }
+
+    #[rstest]
+    #[case("1:4", vec![(1, 3), (2, 2), (3, 1), (4, 0)])]
+    #[case("9:-4:1,5:4,0", vec![(9, 3), (5, 2), (1, 1), (0, 0)])]
+    #[case("", vec![])]
+    fn iter_with_remaining(#[case] numstr: &str, #[case] expected: Vec<(i64, usize)>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
         assert_eq!(
-            NumberRange::default()
-                .parse_str("")
-                .unwrap()
-                .collect::<Vec<f64>>(),
-            vec![]
+            rng.iter_with_remaining().collect::<Vec<(i64, usize)>>(),
+            expected
         );
-        // testing to make sure it removes the old values from iterators
-        let rng = NumberRange::default().parse_str("1:10").unwrap();
-        assert_eq!(rng.parse_str("").unwrap().collect::<Vec<f64>>(), vec![]);
+    }
+
+    #[rstest]
+    #[case("1:10", 3, vec!["1:4", "5:7", "8:10"])]
+    #[case("1,3:5,20:-5:0", 3, vec!["1,3:4", "5,20:-5:15", "10:-5:0"])]
+    #[case("4x7,1:2", 2, vec!["3x7", "7,1:2"])]
+    #[case("1:2", 4, vec!["1", "2", "", ""])]
+    #[case("1:6", 1, vec!["1:6"])]
+    #[case("1:6", 0, vec![])]
+    fn split_into(#[case] numstr: &str, #[case] parts: usize, #[case] expected: Vec<&str>) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        let split = rng.split_into(parts);
+        let strs: Vec<String> = split.iter().map(|p| p.to_string()).collect();
+        assert_eq!(strs, expected);
+        let values: Vec<i64> = split.into_iter().flatten().collect();
+        if parts > 0 {
+            assert_eq!(values, rng.collect::<Vec<i64>>());
+        }
+    }
+
+    #[rstest]
+    #[case("1:100", 2, 50)]
+    #[case("1:100", 7, 14)]
+    #[case("-10:10", 5, 5)]
+    #[case("-10:10", -5, 5)]
+    #[case("10:-1:-10,3", 3, 8)]
+    #[case("1:3:30,4x6", 2, 9)]
+    #[case("-3:3", 0, 1)]
+    #[case("", 2, 0)]
+    fn count_multiples(#[case] numstr: &str, #[case] n: i64, #[case] expected: usize) {
+        let rng = NumberRangeOptions::<i64>::new()
+            .with_repeat_marker('x')
+            .parse(numstr)
+            .unwrap();
+        assert_eq!(rng.count_multiples_of(n), expected);
+        let multiple = |v: i64| if n == 0 { v == 0 } else { v % n == 0 };
+        assert_eq!(rng.count_matching(multiple), expected);
+    }
+
+    #[rstest]
+    fn type_bounds_u128() {
+        let max = u128::MAX;
+        let rng = NumberRange::<u128>::default()
+            .parse_owned(&format!("{}", max))
+            .unwrap();
+        assert_eq!(rng.index_of(max), Some(0));
+        assert_eq!(rng.snap(0), Some(max));
+        let mut rng = NumberRange::<u128>::default()
+            .parse_owned(&format!("0:{}", max))
+            .unwrap();
+        assert_eq!(rng.total_count(), usize::MAX);
+        assert_eq!(rng.index_of(max), None);
+        assert_eq!(rng.index_of(7), Some(7));
+        assert_eq!(rng.count_multiples_of(max), 2);
+        rng.clamp_to_range(max - 1..=max);
+        assert_eq!(rng.collect::<Vec<u128>>(), vec![max - 1, max]);
+    }
+
+    #[rstest]
+    fn type_bounds_i64() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let rng = NumberRange::<i64>::default()
+            .parse_owned(&format!("{}:{},{}:2:{}", min, max, max, min))
+            .unwrap();
+        assert_eq!(rng.numbers[0].count(), usize::MAX);
+        assert_eq!(rng.numbers[1].count(), 0);
+        assert_eq!(rng.total_count(), usize::MAX);
+        assert_eq!(rng.index_of(max), Some(usize::MAX));
+        assert_eq!(rng.count_multiples_of(-1), usize::MAX);
+        assert_eq!(rng.count_multiples_of(min), 2);
+        assert_eq!(rng.count_multiples_of(2), 1 << 63);
+        let rng = NumberRange::<i64>::default()
+            .parse_owned(&format!("{}:{}:{}", max, -(1i64 << 62), min))
+            .unwrap();
+        let last = -(1 << 62) - 1;
+        assert_eq!(rng.numbers[0].last(), Some(last));
+        assert_eq!(rng.index_of(-1), Some(2));
+        assert_eq!(rng.snap(min), Some(last));
+        assert_eq!(rng.snap(0), Some(-1));
+        assert_eq!(rng.count_multiples_of(-1), 4);
+    }
+
+    #[rstest]
+    #[case("1:2:9", Some("1:11:2"))]
+    #[case("1:2:10", Some("1:11:2"))]
+    #[case("3:7", Some("3:8"))]
+    #[case("5", Some("5:6"))]
+    #[case("10:-3:2", Some("10:1:-3"))]
+    #[case("10:-2:0", Some("10::-2"))]
+    #[case("1:3,5", None)]
+    #[case("3:1", None)]
+    #[case("", None)]
+    fn python_slice(#[case] numstr: &str, #[case] expected: Option<&str>) {
+        let rng = NumberRange::<i64>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_python_slice().as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("10:-2:0", Some("10::-2"))]
+    #[case("10:-3:2", Some("10:1:-3"))]
+    #[case("245:5:250", Some("245:255:5"))]
+    #[case("250:5:255", None)]
+    fn python_slice_unsigned(#[case] numstr: &str, #[case] expected: Option<&str>) {
+        let rng = NumberRange::<u8>::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_python_slice().as_deref(), expected);
     }
 
     #[rstest]
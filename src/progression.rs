@@ -0,0 +1,567 @@
+//! Arithmetic progressions over the `u128` keys of the values, so the
+//! set operations can work on the stepped ranges without expanding
+//! them. The keys keep the order and the differences of the values.
+
+/// Intersections tried by [`union_count`] on a group of overlapping
+/// progressions, before it counts them by their periods instead.
+const MAX_INTERSECTIONS: usize = 1 << 16;
+
+/// Values looked at once by [`union_count`], the period of a part with
+/// more values than that is counted page by page.
+const MAX_KEYS: u128 = 1 << 20;
+
+/// Values from `first` to `last` by `step`, the `last` value is always
+/// on the steps, and the `step` is one for a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Progression {
+    pub first: u128,
+    pub step: u128,
+    pub last: u128,
+}
+
+impl Progression {
+    /// Progression with the `last` value moved down onto the steps,
+    /// `None` when it has no values.
+    pub fn new(first: u128, step: u128, last: u128) -> Option<Self> {
+        if first > last || step == 0 {
+            return None;
+        }
+        let last = last - (last - first) % step;
+        let step = if first == last { 1 } else { step };
+        Some(Self { first, step, last })
+    }
+
+    /// Number of values, modulo `2^128` so the full range of `u128`
+    /// is `0`.
+    pub fn count(&self) -> u128 {
+        ((self.last - self.first) / self.step).wrapping_add(1)
+    }
+
+    pub fn contains(&self, key: u128) -> bool {
+        self.first <= key && key <= self.last && (key - self.first).is_multiple_of(self.step)
+    }
+
+    /// Values between `low` and `high` (inclusive).
+    pub fn clamp(&self, low: u128, high: u128) -> Option<Self> {
+        let (low, high) = (low.max(self.first), high.min(self.last));
+        if low > high {
+            return None;
+        }
+        let offset = (low - self.first)
+            .div_ceil(self.step)
+            .checked_mul(self.step)?;
+        Self::new(self.first.checked_add(offset)?, self.step, high)
+    }
+
+    /// Values in both progressions. The first common value comes from
+    /// the chinese remainder theorem, and the step is the lcm of both.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let (low, high) = (self.first.max(other.first), self.last.min(other.last));
+        if low > high {
+            return None;
+        }
+        if self.step == 1 {
+            return other.clamp(low, high);
+        } else if other.step == 1 {
+            return self.clamp(low, high);
+        }
+        let (s1, s2) = (self.step, other.step);
+        let g = gcd(s1, s2);
+        if self.first % g != other.first % g {
+            return None;
+        }
+        // first + s1 * t is common when s1 * t = other.first - first (mod s2)
+        let m = s2 / g;
+        let diff = sub_mod(other.first % s2, self.first % s2, s2) / g;
+        let t = mul_mod(diff % m, inverse((s1 / g) % m, m), m);
+        let mut common = self.first.checked_add(s1.checked_mul(t)?)?;
+        let lcm = (s1 / g).checked_mul(s2);
+        if common < low {
+            let lcm = lcm?;
+            common = common.checked_add((low - common).div_ceil(lcm).checked_mul(lcm)?)?;
+        }
+        match lcm {
+            Some(lcm) => Self::new(common, lcm, high),
+            // the next common value is past the largest key
+            None => Self::new(common, 1, common.min(high)),
+        }
+    }
+}
+
+/// Values in both lists as the overlaps of their progressions, which
+/// can overlap each other. The `other` list has to be sorted.
+pub(crate) fn intersections<'a>(
+    progressions: &'a [Progression],
+    other: &'a [Progression],
+) -> impl Iterator<Item = Progression> + 'a {
+    progressions.iter().flat_map(move |p| {
+        other[..other.partition_point(|q| q.first <= p.last)]
+            .iter()
+            .filter_map(move |q| p.intersect(q))
+    })
+}
+
+/// Number of distinct values in all the progressions, modulo `2^128`
+/// like [`Progression::count()`]. The ones with overlapping spans are
+/// grouped, and each group is counted by inclusion–exclusion of their
+/// common values, or by the periods of its parts when there are too
+/// many of those.
+pub(crate) fn union_count(progressions: &[Progression]) -> u128 {
+    let mut sorted = progressions.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    let mut total: u128 = 0;
+    let mut start = 0;
+    let mut span_end = 0;
+    for i in 0..sorted.len() {
+        if i > start && sorted[i].first > span_end {
+            total = total.wrapping_add(group_count(&sorted[start..i]));
+            start = i;
+        }
+        span_end = if i == start {
+            sorted[i].last
+        } else {
+            span_end.max(sorted[i].last)
+        };
+    }
+    if start < sorted.len() {
+        total = total.wrapping_add(group_count(&sorted[start..]));
+    }
+    total
+}
+
+/// Distinct values of the group sorted by the first values.
+fn group_count(group: &[Progression]) -> u128 {
+    if let [single] = group {
+        return single.count();
+    }
+    let mut total = 0;
+    let mut budget = MAX_INTERSECTIONS;
+    for (i, p) in group.iter().enumerate() {
+        if !include_exclude(group, i + 1, *p, true, &mut total, &mut budget) {
+            return periodic_count(group);
+        }
+    }
+    total
+}
+
+/// Adds (or subtracts) the values common to a subset of the group,
+/// then the ones shared with the later members. `false` once the
+/// budget of intersections runs out.
+fn include_exclude(
+    group: &[Progression],
+    from: usize,
+    common: Progression,
+    add: bool,
+    total: &mut u128,
+    budget: &mut usize,
+) -> bool {
+    *total = if add {
+        total.wrapping_add(common.count())
+    } else {
+        total.wrapping_sub(common.count())
+    };
+    for (j, p) in group.iter().enumerate().skip(from) {
+        if p.first > common.last {
+            break;
+        }
+        if *budget == 0 {
+            return false;
+        }
+        *budget -= 1;
+        if let Some(next) = common.intersect(p) {
+            if !include_exclude(group, j + 1, next, !add, total, budget) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Distinct values of the group counted in its parts, where the
+/// values repeat with the lcm of the steps of the progressions there,
+/// so only one period of each part is looked at.
+fn periodic_count(group: &[Progression]) -> u128 {
+    let mut total: u128 = 0;
+    for_each_part(
+        group,
+        |p| *p,
+        |low, high, active| {
+            let span = high - low;
+            if active.iter().any(|p| p.step == 1) {
+                total = total.wrapping_add(span).wrapping_add(1);
+                return;
+            }
+            let period = active
+                .iter()
+                .try_fold(1u128, |l, p| (l / gcd(l, p.step)).checked_mul(p.step));
+            let window_end = match period {
+                Some(period) if period - 1 < span => low + (period - 1),
+                _ => high,
+            };
+            let count = match period {
+                Some(period) if window_end < high => {
+                    // full periods, and the start of the next one
+                    let (full, rest) = (span / period, span % period + 1);
+                    let keys = window_count(active, low, window_end);
+                    let partial = if rest == period {
+                        keys
+                    } else {
+                        window_count(active, low, low + (rest - 1))
+                    };
+                    full.wrapping_mul(keys).wrapping_add(partial)
+                }
+                _ => window_count(active, low, high),
+            };
+            total = total.wrapping_add(count);
+        },
+    );
+    total
+}
+
+/// Distinct values of the progressions from `low` to `high`, counted
+/// in pages of about [`MAX_KEYS`] values.
+fn window_count(progressions: &[Progression], low: u128, high: u128) -> u128 {
+    // the steps are at least two, so a page has about MAX_KEYS values
+    // at most
+    let width = (MAX_KEYS / progressions.len() as u128).max(1) * 2;
+    let mut count: u128 = 0;
+    let mut page = low;
+    loop {
+        let page_end = page.saturating_add(width - 1).min(high);
+        let clamped: Vec<Progression> = progressions
+            .iter()
+            .filter_map(|p| p.clamp(page, page_end))
+            .collect();
+        count = count.wrapping_add(sorted_keys(&clamped).len() as u128);
+        if page_end == high {
+            return count;
+        }
+        page = page_end + 1;
+    }
+}
+
+/// Calls `f` on each part between the starts and ends of the items'
+/// progressions, with the items spanning all of it, in order.
+fn for_each_part<X: Copy + Ord>(
+    items: &[X],
+    progression: impl Fn(&X) -> Progression,
+    mut f: impl FnMut(u128, u128, &[X]),
+) {
+    let mut sorted = items.to_vec();
+    sorted.sort_by_key(|x| progression(x).first);
+    let mut cuts: Vec<u128> = sorted
+        .iter()
+        .map(&progression)
+        .flat_map(|p| std::iter::once(p.first).chain(p.last.checked_add(1)))
+        .collect();
+    cuts.sort();
+    cuts.dedup();
+    let mut active: Vec<X> = Vec::new();
+    let mut next = 0;
+    for (i, &low) in cuts.iter().enumerate() {
+        let high = cuts.get(i + 1).map_or(u128::MAX, |c| c - 1);
+        active.retain(|x| progression(x).last >= low);
+        while next < sorted.len() && progression(&sorted[next]).first <= low {
+            active.push(sorted[next]);
+            next += 1;
+        }
+        if !active.is_empty() {
+            f(low, high, &active);
+        }
+    }
+}
+
+/// Values in exactly one of the lists, as progressions sorted by
+/// their first values. The span is cut where any progression starts
+/// or ends, within each part the values repeat with the lcm of the
+/// steps there, so only one period of it is checked.
+pub(crate) fn symmetric_difference(a: &[Progression], b: &[Progression]) -> Vec<Progression> {
+    let all: Vec<(Progression, bool)> = a
+        .iter()
+        .map(|p| (*p, true))
+        .chain(b.iter().map(|p| (*p, false)))
+        .collect();
+    let mut result: Vec<Progression> = Vec::new();
+    let mut previous: Vec<usize> = Vec::new();
+    for_each_part(
+        &all,
+        |(p, _)| *p,
+        |low, high, active| {
+            let mut current = Vec::new();
+            for p in part_difference(active, low, high) {
+                // continues the progression from the part before, when
+                // the step across the cut is the same
+                let joined = previous.iter().copied().find(|&j| {
+                    let q = result[j];
+                    let gap = p.first - q.last;
+                    let fits = |r: Progression| r.first == r.last || r.step == gap;
+                    fits(q) && fits(p) && (q.first != q.last || p.first != p.last || gap == 1)
+                });
+                match joined {
+                    Some(j) => {
+                        let q = result[j];
+                        result[j] = Progression {
+                            first: q.first,
+                            step: p.first - q.last,
+                            last: p.last,
+                        };
+                        previous.retain(|&k| k != j);
+                        current.push(j);
+                    }
+                    None => {
+                        current.push(result.len());
+                        result.push(p);
+                    }
+                }
+            }
+            previous = current;
+        },
+    );
+    result
+}
+
+/// Values of the part from `low` to `high` in exactly one side of the
+/// `active` progressions (the ones spanning all of it), `true` is the
+/// first side.
+fn part_difference(active: &[(Progression, bool)], low: u128, high: u128) -> Vec<Progression> {
+    // the runs (and single values) cover the whole part
+    let full = |side: bool| active.iter().any(|(p, s)| *s == side && p.step == 1);
+    let (full_a, full_b) = (full(true), full(false));
+    if full_a && full_b {
+        return Vec::new();
+    }
+    let stepped: Vec<(Progression, bool)> = active
+        .iter()
+        .filter(|(p, _)| p.step != 1)
+        .copied()
+        .collect();
+    // `None` when the period is past the largest key
+    let period = stepped
+        .iter()
+        .try_fold(1u128, |l, (p, _)| (l / gcd(l, p.step)).checked_mul(p.step));
+    let window = match period {
+        Some(period) => high.min(low.saturating_add(period - 1)),
+        None => high,
+    };
+    let window_len = (window - low).saturating_add(1);
+    if full_a != full_b {
+        let others: Vec<Progression> = stepped
+            .iter()
+            .filter(|(_, s)| *s != full_a)
+            .filter_map(|(p, _)| p.clamp(low, high))
+            .collect();
+        let left_out = others
+            .iter()
+            .fold(0u128, |n, p| n.saturating_add(p.count()));
+        if left_out < window_len {
+            // fewer values left out than in a period, the runs
+            // between them are shorter to give
+            let mut runs = Vec::new();
+            let mut from = Some(low);
+            for key in sorted_keys(&others) {
+                if let Some(from) = from.filter(|f| *f < key) {
+                    runs.extend(Progression::new(from, 1, key - 1));
+                }
+                from = key.checked_add(1);
+            }
+            runs.extend(from.and_then(|f| Progression::new(f, 1, high)));
+            return runs;
+        }
+    }
+    let keys = |side: bool| {
+        let clamped: Vec<Progression> = stepped
+            .iter()
+            .filter(|(_, s)| *s == side)
+            .filter_map(|(p, _)| p.clamp(low, window))
+            .collect();
+        sorted_keys(&clamped)
+    };
+    let only: Vec<u128> = if full_a || full_b {
+        // the values of the window left out by the other side
+        let mut others = keys(!full_a).into_iter().peekable();
+        (low..=window)
+            .filter(|k| others.next_if_eq(k).is_none())
+            .collect()
+    } else {
+        // only the values of the progressions can be in one side, the
+        // ones in both are there twice
+        let mut both = [keys(true), keys(false)].concat();
+        both.sort();
+        both.chunk_by(|x, y| x == y)
+            .filter(|keys| keys.len() == 1)
+            .map(|keys| keys[0])
+            .collect()
+    };
+    only.into_iter()
+        .filter_map(|k| match period {
+            Some(period) => Progression::new(k, period, high),
+            None => Progression::new(k, 1, k),
+        })
+        .collect()
+}
+
+/// All the values of the progressions, sorted and without repeats.
+fn sorted_keys(progressions: &[Progression]) -> Vec<u128> {
+    let mut keys: Vec<u128> = progressions
+        .iter()
+        .flat_map(|p| {
+            std::iter::successors(Some(p.first), move |k| {
+                k.checked_add(p.step).filter(|k| *k <= p.last)
+            })
+        })
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a * b (mod m)` for `a` and `b` below `m`, without overflowing.
+fn mul_mod(mut a: u128, mut b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    product
+}
+
+/// Inverse of `a (mod m)` for the coprime `a` and `m`.
+fn inverse(a: u128, m: u128) -> u128 {
+    if m == 1 {
+        return 0;
+    }
+    let (mut r0, mut r1) = (m, a);
+    let (mut t0, mut t1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, sub_mod(t0, mul_mod(q % m, t1, m), m));
+    }
+    t0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn prog(first: u128, step: u128, last: u128) -> Progression {
+        Progression::new(first, step, last).unwrap()
+    }
+
+    fn values(p: Option<Progression>) -> Vec<u128> {
+        p.map(|p| (p.first..=p.last).step_by(p.step as usize).collect())
+            .unwrap_or_default()
+    }
+
+    #[rstest]
+    #[case(prog(0, 2, 20), prog(0, 3, 20), vec![0, 6, 12, 18])]
+    #[case(prog(1, 4, 40), prog(3, 6, 40), vec![9, 21, 33])]
+    #[case(prog(0, 2, 20), prog(1, 2, 21), vec![])]
+    #[case(prog(5, 1, 9), prog(0, 3, 30), vec![6, 9])]
+    #[case(prog(10, 5, 100), prog(40, 1, 41), vec![40])]
+    #[case(prog(7, 1, 7), prog(1, 3, 10), vec![7])]
+    fn progression_intersect(
+        #[case] p1: Progression,
+        #[case] p2: Progression,
+        #[case] common: Vec<u128>,
+    ) {
+        assert_eq!(values(p1.intersect(&p2)), common);
+        assert_eq!(values(p2.intersect(&p1)), common);
+    }
+
+    #[rstest]
+    fn progression_intersect_large() {
+        let p1 = prog(0, u128::MAX / 3, u128::MAX);
+        let p2 = prog(u128::MAX / 3, 1 << 100, u128::MAX);
+        assert_eq!(
+            p1.intersect(&p2),
+            Some(prog(u128::MAX / 3, 1, u128::MAX / 3))
+        );
+        let p1 = prog(1, (1 << 127) - 1, u128::MAX);
+        let p2 = prog(0, 1 << 126, u128::MAX);
+        assert_eq!(p1.intersect(&p2), Some(prog(1 << 127, 1, 1 << 127)));
+        let p2 = prog(2, 1 << 126, u128::MAX);
+        assert_eq!(p1.intersect(&p2), None);
+    }
+
+    #[rstest]
+    #[case(vec![prog(0, 2, 100), prog(0, 3, 100)], 68)]
+    #[case(vec![prog(0, 2, 20), prog(1, 2, 21)], 22)]
+    #[case(vec![prog(0, 1, 10), prog(5, 1, 15), prog(0, 5, 30)], 19)]
+    #[case(vec![prog(0, 2, 4_000_000_000), prog(0, 3, 4_000_000_000)], 2_666_666_668)]
+    #[case(vec![prog(0, 1, u128::MAX)], 0)]
+    fn progression_union_count(#[case] progressions: Vec<Progression>, #[case] count: u128) {
+        assert_eq!(union_count(&progressions), count);
+    }
+
+    #[rstest]
+    #[case(vec![prog(0, 2, 20)], vec![prog(0, 1, 20)], vec![prog(1, 2, 19)])]
+    #[case(vec![prog(0, 2, 12)], vec![prog(0, 3, 12)], vec![prog(2, 6, 8), prog(3, 6, 9), prog(4, 6, 10)])]
+    #[case(vec![prog(0, 1, 10)], vec![prog(5, 1, 15)], vec![prog(0, 1, 4), prog(11, 1, 15)])]
+    #[case(vec![prog(0, 2, 4_000_000_000)], vec![prog(1, 1, 10)], vec![prog(0, 1, 0), prog(1, 2, 9), prog(12, 2, 4_000_000_000)])]
+    #[case(vec![prog(0, 2, 10)], vec![prog(0, 2, 10)], vec![])]
+    #[case(vec![], vec![prog(0, 1, u128::MAX)], vec![prog(0, 1, u128::MAX)])]
+    fn progression_symmetric_difference(
+        #[case] a: Vec<Progression>,
+        #[case] b: Vec<Progression>,
+        #[case] expected: Vec<Progression>,
+    ) {
+        assert_eq!(symmetric_difference(&a, &b), expected);
+        assert_eq!(symmetric_difference(&b, &a), expected);
+    }
+
+    #[rstest]
+    #[case((2..30).map(|s| prog(0, s, 1000)).collect())]
+    #[case((2..30).map(|s| prog(s * 7, s, 5000 - s)).collect())]
+    #[case((0..40).map(|s| prog(s, 2 + s % 3, 3000 + s * 10)).collect())]
+    fn progression_union_count_periodic(#[case] progressions: Vec<Progression>) {
+        let values: std::collections::BTreeSet<u128> = progressions
+            .iter()
+            .flat_map(|p| (p.first..=p.last).step_by(p.step as usize))
+            .collect();
+        assert_eq!(union_count(&progressions), values.len() as u128);
+    }
+
+    #[rstest]
+    fn progression_union_count_paged() {
+        // too many intersections, and too many values for a single page
+        let progressions: Vec<Progression> = (2..30).map(|s| prog(s, s, 3_000_000)).collect();
+        let mut values = vec![false; 3_000_001];
+        for s in 2..30 {
+            (s..=3_000_000).step_by(s).for_each(|v| values[v] = true);
+        }
+        let expected = values.iter().filter(|v| **v).count();
+        assert_eq!(union_count(&progressions), expected as u128);
+    }
+}
@@ -0,0 +1,179 @@
+//! Ranges of roman numerals like `"I:V"` or `"X:-II:II"`, from `I`
+//! (1) to `MMMCMXCIX` (3999). The numerals are parsed in either
+//! case, and rendered back in uppercase.
+use crate::{NumberRange, NumberRangeError, NumberRangeOptions};
+use anyhow::{Context, Result};
+
+const NUMERALS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+const MAX_ROMAN: u32 = 3999;
+
+/// Range of roman numerals, the step is one when it's not given. It's
+/// stored as a [`NumberRange<u32>`] of the values, so it uses the
+/// same separators from the [`NumberRangeOptions<u32>`].
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use number_range::RomanRange;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let rng = RomanRange::default().parse_str("i:iii,X")?;
+/// assert_eq!(rng.to_string(), "I:III,X");
+/// assert_eq!(rng.range.iter().collect::<Vec<u32>>(), vec![1, 2, 3, 10]);
+/// assert_eq!(rng.collect::<Vec<String>>(), vec!["I", "II", "III", "X"]);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct RomanRange<'a> {
+    pub range: NumberRange<'a, u32>,
+}
+
+impl<'a> RomanRange<'a> {
+    /// New RomanRange struct from NumberRangeOptions
+    pub fn from_options(options: NumberRangeOptions<u32>) -> Self {
+        Self {
+            range: NumberRange::from_options(options),
+        }
+    }
+
+    /// Parse the human readable string (`numstr`) of roman numerals,
+    /// so it takes the same defaults and steps as the other ranges.
+    pub fn parse_str(mut self, numstr: &'a str) -> Result<Self> {
+        self.range.options.value_parser = Some(parse_roman);
+        self.range = self.range.parse_str(numstr)?;
+        Ok(self)
+    }
+}
+
+/// Parses the roman numeral into its value, only the standard form
+/// is accepted (`IV`, not `IIII`).
+fn parse_roman(numeral: &str) -> Result<u32> {
+    let upper = numeral.to_uppercase();
+    let mut rest = upper.as_str();
+    let mut value = 0;
+    for (symbol, v) in NUMERALS {
+        while let Some(r) = rest.strip_prefix(symbol) {
+            value += v;
+            rest = r;
+        }
+    }
+    if !rest.is_empty() || value == 0 || value > MAX_ROMAN || to_roman(value) != upper {
+        return Err::<u32, anyhow::Error>(NumberRangeError {}.into())
+            .with_context(|| format!("{} Not a Roman numeral", numeral));
+    }
+    Ok(value)
+}
+
+/// Roman numeral of the value, the values outside of `1..=3999` are
+/// written as the number itself.
+fn to_roman(mut value: u32) -> String {
+    if value == 0 || value > MAX_ROMAN {
+        return value.to_string();
+    }
+    let mut numeral = String::new();
+    for (symbol, v) in NUMERALS {
+        while value >= v {
+            numeral.push_str(symbol);
+            value -= v;
+        }
+    }
+    numeral
+}
+
+impl<'a> std::fmt::Display for RomanRange<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let options = &self.range.options;
+        let repr = self
+            .range
+            .format_numbers(options.list_sep, options.range_sep, false, &|v| {
+                to_roman(*v)
+            });
+        write!(f, "{}", repr)
+    }
+}
+
+impl<'a> Iterator for RomanRange<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.range.next().map(to_roman)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("I:V", vec![1, 2, 3, 4, 5], "I:V")]
+    #[case("i:ii:ix", vec![1, 3, 5, 7, 9], "I:II:IX")]
+    #[case("X:-III:I", vec![10, 7, 4, 1], "X:-III:I")]
+    #[case("XL, MCMXCIV", vec![40, 1994], "XL,MCMXCIV")]
+    #[case("", vec![], "")]
+    fn roman_range(#[case] numstr: &str, #[case] values: Vec<u32>, #[case] display: &str) {
+        let rng = RomanRange::default().parse_str(numstr).unwrap();
+        assert_eq!(rng.to_string(), display);
+        assert_eq!(rng.range.iter().collect::<Vec<u32>>(), values);
+    }
+
+    #[rstest]
+    #[case("I:", vec![1, 2, 3, 4, 5])]
+    #[case(":III", vec![2, 3])]
+    #[case("IV:II:", vec![4])]
+    #[case("II:III:", vec![2, 5])]
+    fn roman_range_defaults(#[case] numstr: &str, #[case] values: Vec<u32>) {
+        let options = NumberRangeOptions::new()
+            .with_default_start(2)
+            .with_default_end(5);
+        let rng = RomanRange::from_options(options).parse_str(numstr).unwrap();
+        assert_eq!(rng.range.iter().collect::<Vec<u32>>(), values);
+    }
+
+    #[rstest]
+    fn roman_values() {
+        let rng = RomanRange::default().parse_str("I:V").unwrap();
+        assert_eq!(rng.range.iter().collect::<Vec<u32>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(rng.collect::<Vec<String>>().join(","), "I,II,III,IV,V");
+    }
+
+    #[rstest]
+    #[case(1, "I")]
+    #[case(4, "IV")]
+    #[case(14, "XIV")]
+    #[case(3999, "MMMCMXCIX")]
+    #[case(0, "0")]
+    #[case(4000, "4000")]
+    fn roman_numeral(#[case] value: u32, #[case] numeral: &str) {
+        assert_eq!(to_roman(value), numeral);
+    }
+
+    #[rstest]
+    #[case("1:5")]
+    #[case("IIII")]
+    #[case("VX")]
+    #[case("MMMM")]
+    #[case("I:V:X:L")]
+    #[case("I:")]
+    #[case("X:-IIII:I")]
+    #[case("I:0:V")]
+    #[should_panic]
+    fn roman_range_error(#[case] numstr: &str) {
+        RomanRange::default().parse_str(numstr).unwrap();
+    }
+}
@@ -0,0 +1,115 @@
+//! Serialize the [`NumberRange<T>`] as its human readable string
+//! (e.g. `"1,3:10"`), it's deserialized from either that string or
+//! an array of the numbers (e.g. `[1, 3, 4, 5]`), both with the
+//! default options.
+use crate::NumberRange;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+impl<'a, T: fmt::Display + num::One + std::cmp::PartialEq> Serialize for NumberRange<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Visitor for the string or the array of numbers
+struct NumberRangeVisitor<'a, T>(PhantomData<NumberRange<'a, T>>);
+
+impl<'de, 'a, T> Visitor<'de> for NumberRangeVisitor<'a, T>
+where
+    T: std::str::FromStr + num::Num + num::NumCast + Copy + std::cmp::Ord + Deserialize<'de>,
+    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Value = NumberRange<'a, T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number range string or an array of numbers")
+    }
+
+    fn visit_str<E: de::Error>(self, numstr: &str) -> Result<Self::Value, E> {
+        NumberRange::default()
+            .parse_owned(numstr)
+            .map_err(|err| E::custom(format!("{:#}", err)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(NumberRange::default().from_vec_nosort(&values, None))
+    }
+}
+
+impl<'de, 'a, T> Deserialize<'de> for NumberRange<'a, T>
+where
+    T: std::str::FromStr + num::Num + num::NumCast + Copy + std::cmp::Ord + Deserialize<'de>,
+    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NumberRangeVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OwnedNumberRange;
+    use rstest::rstest;
+    use serde::de::value::{Error, SeqDeserializer};
+    use serde::de::IntoDeserializer;
+    use serde_test::Token;
+
+    fn from_str(numstr: &str) -> Result<OwnedNumberRange<i64>, Error> {
+        OwnedNumberRange::deserialize(numstr.into_deserializer())
+    }
+
+    fn from_array<V: IntoDeserializer<'static, Error>>(
+        values: Vec<V>,
+    ) -> Result<OwnedNumberRange<u32>, Error> {
+        OwnedNumberRange::deserialize(SeqDeserializer::new(values.into_iter()))
+    }
+
+    #[rstest]
+    #[case("1,3:10", "1,3:10")]
+    #[case("10:-2:4, 1", "10:-2:4,1")]
+    #[case("", "")]
+    fn deserialize_str(#[case] numstr: &str, #[case] display: &str) {
+        assert_eq!(from_str(numstr).unwrap().to_string(), display);
+    }
+
+    #[rstest]
+    #[case(vec![1, 3, 4, 5, 6, 7, 8, 9, 10], "1,3:10")]
+    #[case(vec![5, 1, 2, 3], "5,1:3")]
+    #[case(vec![], "")]
+    fn deserialize_array(#[case] values: Vec<u32>, #[case] display: &str) {
+        assert_eq!(from_array(values).unwrap().to_string(), display);
+    }
+
+    #[rstest]
+    fn deserialize_equivalent() {
+        let from_str = from_str("1,3:6").unwrap();
+        let from_array = from_array(vec![1, 3, 4, 5, 6]).unwrap();
+        assert_eq!(
+            from_str.collect::<Vec<i64>>(),
+            from_array.map(i64::from).collect::<Vec<i64>>()
+        );
+    }
+
+    #[rstest]
+    fn deserialize_error() {
+        assert!(from_str("1,x").is_err());
+        assert!(from_array(vec![1, -2]).is_err());
+        assert!(from_array(vec![1.5]).is_err());
+        let number: de::value::U32Deserializer<Error> = 5u32.into_deserializer();
+        assert!(OwnedNumberRange::<u32>::deserialize(number).is_err());
+    }
+
+    #[rstest]
+    fn serialize() {
+        let rng = NumberRange::<i64>::default().parse_str("1, 3:10").unwrap();
+        serde_test::assert_ser_tokens(&rng, &[Token::Str("1,3:10")]);
+    }
+}